@@ -0,0 +1,231 @@
+use core::num::{Zero, One};
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+
+/**
+ * An angle in radians
+ *
+ * The type of an angle documents its units, and prevents an angle in degrees
+ * from being passed to a function expecting one in radians.
+ */
+#[deriving(Eq, Ord)]
+pub struct Rad<T>(T);
+
+/**
+ * An angle in degrees
+ */
+#[deriving(Eq, Ord)]
+pub struct Deg<T>(T);
+
+/**
+ * The operations common to angles in any unit
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the value of the angle. Should be a floating point type.
+ */
+pub trait Angle<T> {
+    /**
+     * # Return value
+     *
+     * A complete turn, ie: `2π` radians or `360` degrees
+     */
+    fn full_turn() -> Self;
+
+    /**
+     * # Return value
+     *
+     * Half of a turn, ie: `π` radians or `180` degrees
+     */
+    fn half_turn() -> Self;
+
+    /**
+     * # Return value
+     *
+     * The angle in radians
+     */
+    fn to_rad(&self) -> Rad<T>;
+
+    /**
+     * # Return value
+     *
+     * The angle in degrees
+     */
+    fn to_deg(&self) -> Deg<T>;
+
+    /**
+     * # Return value
+     *
+     * The equivalent angle in the range `[0, 2π)`
+     */
+    fn normalize(&self) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The equivalent angle in the range `(-π, π]`
+     */
+    fn normalize_signed(&self) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The sine of the angle
+     */
+    fn sin(&self) -> T;
+
+    /**
+     * # Return value
+     *
+     * The cosine of the angle
+     */
+    fn cos(&self) -> T;
+
+    /**
+     * # Return value
+     *
+     * The tangent of the angle
+     */
+    fn tan(&self) -> T;
+}
+
+/**
+ * # Return value
+ *
+ * `value` wrapped into the range `[0, full_turn)`
+ */
+#[inline(always)]
+fn wrap<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T>>(value: T, full_turn: T) -> T {
+    let wrapped = value - full_turn * floor(value / full_turn);
+
+    // rounding can give exactly `full_turn` for small negative values
+    if wrapped >= full_turn { wrapped - full_turn } else { wrapped }
+}
+
+/**
+ * # Return value
+ *
+ * `value` wrapped into the range `(-half_turn, half_turn]`
+ */
+#[inline(always)]
+fn wrap_signed<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T>>(value: T, half_turn: T) -> T {
+    let full_turn = half_turn + half_turn;
+    let wrapped = wrap(value, full_turn);
+
+    if wrapped > half_turn { wrapped - full_turn } else { wrapped }
+}
+
+impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T>> Angle<T> for Rad<T> {
+    #[inline(always)]
+    fn full_turn() -> Rad<T> {
+        let _2: T = num::cast(2);
+        Rad(_2 * Float::pi())
+    }
+
+    #[inline(always)]
+    fn half_turn() -> Rad<T> {
+        Rad(Float::pi())
+    }
+
+    #[inline(always)]
+    fn to_rad(&self) -> Rad<T> { *self }
+
+    #[inline(always)]
+    fn to_deg(&self) -> Deg<T> { Deg(degrees(**self)) }
+
+    #[inline(always)]
+    fn normalize(&self) -> Rad<T> {
+        let full: Rad<T> = Angle::full_turn();
+        Rad(wrap(**self, *full))
+    }
+
+    #[inline(always)]
+    fn normalize_signed(&self) -> Rad<T> {
+        let half: Rad<T> = Angle::half_turn();
+        Rad(wrap_signed(**self, *half))
+    }
+
+    #[inline(always)] fn sin(&self) -> T { sin(**self) }
+    #[inline(always)] fn cos(&self) -> T { cos(**self) }
+    #[inline(always)] fn tan(&self) -> T { tan(**self) }
+}
+
+impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T>> Angle<T> for Deg<T> {
+    #[inline(always)]
+    fn full_turn() -> Deg<T> { Deg(num::cast(360)) }
+
+    #[inline(always)]
+    fn half_turn() -> Deg<T> { Deg(num::cast(180)) }
+
+    #[inline(always)]
+    fn to_rad(&self) -> Rad<T> { Rad(radians(**self)) }
+
+    #[inline(always)]
+    fn to_deg(&self) -> Deg<T> { *self }
+
+    #[inline(always)]
+    fn normalize(&self) -> Deg<T> {
+        Deg(wrap(**self, num::cast(360)))
+    }
+
+    #[inline(always)]
+    fn normalize_signed(&self) -> Deg<T> {
+        Deg(wrap_signed(**self, num::cast(180)))
+    }
+
+    #[inline(always)] fn sin(&self) -> T { sin(radians(**self)) }
+    #[inline(always)] fn cos(&self) -> T { cos(radians(**self)) }
+    #[inline(always)] fn tan(&self) -> T { tan(radians(**self)) }
+}
+
+macro_rules! angle_ops(
+    ($Angle:ident) => (
+        impl<T:Copy + Add<T,T>> Add<$Angle<T>, $Angle<T>> for $Angle<T> {
+            #[inline(always)]
+            fn add(&self, other: &$Angle<T>) -> $Angle<T> { $Angle(**self + **other) }
+        }
+
+        impl<T:Copy + Sub<T,T>> Sub<$Angle<T>, $Angle<T>> for $Angle<T> {
+            #[inline(always)]
+            fn sub(&self, other: &$Angle<T>) -> $Angle<T> { $Angle(**self - **other) }
+        }
+
+        impl<T:Copy + Mul<T,T>> Mul<T, $Angle<T>> for $Angle<T> {
+            #[inline(always)]
+            fn mul(&self, value: &T) -> $Angle<T> { $Angle(**self * *value) }
+        }
+
+        impl<T:Copy + Div<T,T>> Div<T, $Angle<T>> for $Angle<T> {
+            #[inline(always)]
+            fn div(&self, value: &T) -> $Angle<T> { $Angle(**self / *value) }
+        }
+
+        impl<T:Copy + Neg<T>> Neg<$Angle<T>> for $Angle<T> {
+            #[inline(always)]
+            fn neg(&self) -> $Angle<T> { $Angle(-**self) }
+        }
+
+        impl<T:Copy + Float + FuzzyEq<T>> FuzzyEq<T> for $Angle<T> {
+            #[inline(always)]
+            fn fuzzy_eq(&self, other: &$Angle<T>) -> bool {
+                self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+            }
+
+            #[inline(always)]
+            fn fuzzy_eq_eps(&self, other: &$Angle<T>, epsilon: &T) -> bool {
+                (**self).fuzzy_eq_eps(&**other, epsilon)
+            }
+        }
+    )
+)
+
+angle_ops!(Rad)
+angle_ops!(Deg)
+
+// Rust-style type aliases
+pub type Radf   = Rad<float>;
+pub type Radf32 = Rad<f32>;
+pub type Radf64 = Rad<f64>;
+pub type Degf   = Deg<float>;
+pub type Degf32 = Deg<f32>;
+pub type Degf64 = Deg<f64>;
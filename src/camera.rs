@@ -0,0 +1,548 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use angle::{Angle, Rad, Deg};
+use mat::{BaseMat, Mat3, BaseMat3, Mat4, BaseMat4};
+use quat::Quat;
+use vec::{Vec2, BaseVec2, Vec3, BaseVec3, Vec4, BaseVec4, AffineVec, NumVec, NumVec3, FromHomogeneous};
+
+/**
+ * Create a view matrix for a camera at `eye` looking towards `target`
+ *
+ * This is the equivalent of the [gluLookAt]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluLookAt.xml) function.
+ *
+ * # Arguments
+ *
+ * * `eye`    - the position of the camera
+ * * `target` - the point that the camera looks at
+ * * `up`     - the direction that should appear upwards. This must not be
+ *              parallel to the direction from `eye` to `target`.
+ */
+pub fn look_at<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(eye: &Vec3<T>, target: &Vec3<T>, up: &Vec3<T>) -> Mat4<T> {
+    // the columns of `Mat3::look_at` are the up, right and forward directions
+    let m: Mat3<T> = BaseMat3::look_at(&target.sub_v(eye), up);
+    view_from_axes(&m.y.normalize(), &m.x, &(-m.z), eye)
+}
+
+/**
+ * Create a view matrix for a camera with the orientation `rot`, at the
+ * position `eye`
+ *
+ * The camera looks down the negative `z` axis of its orientation, with the
+ * positive `y` axis upwards, as in OpenGL.
+ */
+#[inline(always)]
+pub fn view_from_rotation<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(rot: &Quat<T>, eye: &Vec3<T>) -> Mat4<T> {
+    let m = rot.normalize().to_mat3();
+    view_from_axes(&m.x, &m.y, &m.z, eye)
+}
+
+/**
+ * Create a view matrix from the orthonormal axes of a camera
+ *
+ * # Arguments
+ *
+ * * `right` - the direction to the right of the camera
+ * * `up`    - the upwards direction of the camera
+ * * `back`  - the direction opposite to the one the camera is looking in
+ * * `eye`   - the position of the camera
+ */
+#[inline(always)]
+fn view_from_axes<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(right: &Vec3<T>, up: &Vec3<T>, back: &Vec3<T>, eye: &Vec3<T>) -> Mat4<T> {
+    BaseMat4::new(right.x, up.x, back.x, zero(),
+                  right.y, up.y, back.y, zero(),
+                  right.z, up.z, back.z, zero(),
+                  -right.dot(eye), -up.dot(eye), -back.dot(eye), one())
+}
+
+/**
+ * # Return value
+ *
+ * The orientation of a camera that has turned by `yaw` around the world `y`
+ * axis, and then by `pitch` around its own `x` axis
+ */
+#[inline(always)]
+fn yaw_pitch<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(yaw: Rad<T>, pitch: Rad<T>) -> Quat<T> {
+    Quat::from_rad_y(yaw).mul_q(&Quat::from_rad_x(pitch))
+}
+
+/**
+ * # Return value
+ *
+ * `pitch` clamped to the range `[-limit, limit]`
+ */
+#[inline(always)]
+fn clamp_pitch<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(pitch: Rad<T>, limit: Rad<T>) -> Rad<T> {
+    if pitch > limit { limit } else if pitch < -limit { -limit } else { pitch }
+}
+
+/**
+ * A first person camera, which turns around the world `y` axis and tilts up
+ * and down, but never rolls
+ *
+ * At zero yaw and pitch the camera looks down the negative `z` axis. A
+ * positive yaw turns the camera to the left, and a positive pitch tilts it
+ * upwards.
+ *
+ * # Fields
+ *
+ * * `position`    - the position of the camera
+ * * `yaw`         - the rotation around the world `y` axis, in the range
+ *                   `(-π, π]`
+ * * `pitch`       - the rotation around the camera's `x` axis, in the range
+ *                   `[-pitch_limit, pitch_limit]`
+ * * `pitch_limit` - the largest pitch, which should be less than `π / 2` so
+ *                   that the camera does not flip over
+ */
+#[deriving(Eq)]
+pub struct FpsCamera<T> {
+    position:    Vec3<T>,
+    yaw:         Rad<T>,
+    pitch:       Rad<T>,
+    pitch_limit: Rad<T>,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FpsCamera<T> {
+    /**
+     * Construct a camera with a pitch limit of 89 degrees
+     */
+    #[inline(always)]
+    fn new(position: Vec3<T>, yaw: Rad<T>, pitch: Rad<T>) -> FpsCamera<T> {
+        let _89: T = num::cast(89);
+        let limit = Deg(_89).to_rad();
+        FpsCamera { position:    position,
+                    yaw:         yaw.normalize_signed(),
+                    pitch:       clamp_pitch(pitch, limit),
+                    pitch_limit: limit }
+    }
+
+    /**
+     * Turn the camera, for example by an amount proportional to the movement
+     * of the mouse
+     */
+    #[inline(always)]
+    fn rotate(&mut self, yaw: Rad<T>, pitch: Rad<T>) {
+        self.yaw = (self.yaw + yaw).normalize_signed();
+        self.pitch = clamp_pitch(self.pitch + pitch, self.pitch_limit);
+    }
+
+    /**
+     * Move the camera along its own axes, for example `(0, 0, -speed * dt)`
+     * to move forwards
+     */
+    #[inline(always)]
+    fn translate(&mut self, offset: &Vec3<T>) {
+        self.position = self.position.add_v(&self.orientation().mul_v(offset));
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation from the axes of the camera to the world axes
+     */
+    #[inline(always)]
+    fn orientation(&self) -> Quat<T> {
+        yaw_pitch(self.yaw, self.pitch)
+    }
+
+    /**
+     * # Return value
+     *
+     * The direction the camera is looking in
+     */
+    #[inline(always)]
+    fn forward(&self) -> Vec3<T> {
+        self.orientation().mul_v(&BaseVec3::new(zero(), zero(), -one::<T>()))
+    }
+
+    /**
+     * # Return value
+     *
+     * The view matrix of the camera
+     */
+    #[inline(always)]
+    fn view(&self) -> Mat4<T> {
+        view_from_rotation(&self.orientation(), &self.position)
+    }
+}
+
+/**
+ * A turntable camera, which orbits around a target point
+ *
+ * The camera turns around the world `y` axis through the target, and tilts up
+ * and down, but never rolls. At zero yaw and pitch the camera is on the
+ * positive `z` side of the target, looking towards it.
+ *
+ * # Fields
+ *
+ * * `target`      - the point that the camera orbits around
+ * * `distance`    - the distance of the camera from the target
+ * * `yaw`         - the rotation around the world `y` axis, in the range
+ *                   `(-π, π]`
+ * * `pitch`       - the elevation of the camera, in the range
+ *                   `[-pitch_limit, pitch_limit]`. A positive pitch moves the
+ *                   camera above the target, looking down.
+ * * `pitch_limit` - the largest pitch, which should be less than `π / 2` so
+ *                   that the camera does not flip over
+ */
+#[deriving(Eq)]
+pub struct OrbitCamera<T> {
+    target:      Vec3<T>,
+    distance:    T,
+    yaw:         Rad<T>,
+    pitch:       Rad<T>,
+    pitch_limit: Rad<T>,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> OrbitCamera<T> {
+    /**
+     * Construct a camera with a pitch limit of 89 degrees
+     */
+    #[inline(always)]
+    fn new(target: Vec3<T>, distance: T, yaw: Rad<T>, pitch: Rad<T>) -> OrbitCamera<T> {
+        let _89: T = num::cast(89);
+        let limit = Deg(_89).to_rad();
+        OrbitCamera { target:      target,
+                      distance:    distance,
+                      yaw:         yaw.normalize_signed(),
+                      pitch:       clamp_pitch(pitch, limit),
+                      pitch_limit: limit }
+    }
+
+    /**
+     * Orbit the camera around the target
+     */
+    #[inline(always)]
+    fn rotate(&mut self, yaw: Rad<T>, pitch: Rad<T>) {
+        self.yaw = (self.yaw + yaw).normalize_signed();
+        self.pitch = clamp_pitch(self.pitch + pitch, self.pitch_limit);
+    }
+
+    /**
+     * Multiply the distance from the target by `factor`, which should be
+     * positive
+     */
+    #[inline(always)]
+    fn zoom(&mut self, factor: T) {
+        self.distance = self.distance * factor;
+    }
+
+    /**
+     * Move the target and the camera together, parallel to the image plane
+     */
+    #[inline(always)]
+    fn pan(&mut self, right: T, up: T) {
+        let offset = self.orientation().mul_v(&BaseVec3::new(right, up, zero()));
+        self.target = self.target.add_v(&offset);
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation from the axes of the camera to the world axes
+     */
+    #[inline(always)]
+    fn orientation(&self) -> Quat<T> {
+        yaw_pitch(self.yaw, -self.pitch)
+    }
+
+    /**
+     * # Return value
+     *
+     * The position of the camera
+     */
+    #[inline(always)]
+    fn eye(&self) -> Vec3<T> {
+        let offset = self.orientation().mul_v(&BaseVec3::new(zero(), zero(), self.distance));
+        self.target.add_v(&offset)
+    }
+
+    /**
+     * # Return value
+     *
+     * The view matrix of the camera
+     */
+    #[inline(always)]
+    fn view(&self) -> Mat4<T> {
+        view_from_rotation(&self.orientation(), &self.eye())
+    }
+}
+
+/**
+ * A camera controlled by Shoemake's arcball, which orbits around a target
+ * point
+ *
+ * Dragging the cursor across the window rotates the scene as if it were
+ * inside a ball filling the window, which the cursor is rolling. Unlike
+ * `OrbitCamera`, this can rotate the camera in any direction.
+ *
+ * - [ARCBALL: A User Interface for Specifying Three-Dimensional Orientation
+ *   Using a Mouse](http://www.talisman.org/~erlkonig/misc/shoemake92-arcball.pdf)
+ *
+ * # Fields
+ *
+ * * `target`      - the point that the camera orbits around
+ * * `distance`    - the distance of the camera from the target
+ * * `orientation` - the rotation from the axes of the camera to the world
+ *                   axes
+ */
+#[deriving(Eq)]
+pub struct ArcballCamera<T> {
+    target:      Vec3<T>,
+    distance:    T,
+    orientation: Quat<T>,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> ArcballCamera<T> {
+    #[inline(always)]
+    fn new(target: Vec3<T>, distance: T, orientation: Quat<T>) -> ArcballCamera<T> {
+        ArcballCamera { target: target, distance: distance, orientation: orientation }
+    }
+
+    /**
+     * Rotate the camera for a movement of the cursor from `from` to `to`
+     *
+     * The cursor positions are in normalized window coordinates, where the
+     * window spans `[-1, 1]` on each axis, with the positive `y` axis
+     * upwards. The ball is the unit circle in these coordinates. Dragging from
+     * the centre of the window to the edge rotates the scene by 180 degrees.
+     */
+    fn rotate(&mut self, from: &Vec2<T>, to: &Vec2<T>) {
+        let a = arcball_point(from);
+        let b = arcball_point(to);
+
+        // the rotation by twice the angle between the points on the ball
+        let drag = Quat::from_sv(a.dot(&b), a.cross(&b));
+
+        // rotate the scene by `drag` in eye space, ie: the camera the other way
+        self.orientation = self.orientation.mul_q(&drag.conjugate()).normalize();
+    }
+
+    /**
+     * Multiply the distance from the target by `factor`, which should be
+     * positive
+     */
+    #[inline(always)]
+    fn zoom(&mut self, factor: T) {
+        self.distance = self.distance * factor;
+    }
+
+    /**
+     * # Return value
+     *
+     * The position of the camera
+     */
+    #[inline(always)]
+    fn eye(&self) -> Vec3<T> {
+        let offset = self.orientation.mul_v(&BaseVec3::new(zero(), zero(), self.distance));
+        self.target.add_v(&offset)
+    }
+
+    /**
+     * # Return value
+     *
+     * The view matrix of the camera
+     */
+    #[inline(always)]
+    fn view(&self) -> Mat4<T> {
+        view_from_rotation(&self.orientation, &self.eye())
+    }
+}
+
+/**
+ * Map a point in normalized window coordinates onto the unit hemisphere
+ * facing the viewer. Points outside the unit circle are mapped to the nearest
+ * point on its edge.
+ */
+pub fn arcball_point<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(p: &Vec2<T>) -> Vec3<T> {
+    let length2 = p.x * p.x + p.y * p.y;
+
+    if length2 > one() {
+        let length = sqrt(length2);
+        BaseVec3::new(p.x / length, p.y / length, zero())
+    } else {
+        BaseVec3::new(p.x, p.y, sqrt(one::<T>() - length2))
+    }
+}
+
+/**
+ * A ray, such as a primary ray of a ray tracer or a picking ray
+ *
+ * # Fields
+ *
+ * * `origin`    - the point that the ray starts from
+ * * `direction` - the normalized direction of the ray
+ */
+#[deriving(Eq)]
+pub struct Ray<T> {
+    origin:    Vec3<T>,
+    direction: Vec3<T>,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Ray<T> {
+    #[inline(always)]
+    fn new(origin: Vec3<T>, direction: Vec3<T>) -> Ray<T> {
+        Ray { origin: origin, direction: direction }
+    }
+
+    /**
+     * # Return value
+     *
+     * The point at the distance `t` along the ray
+     */
+    #[inline(always)]
+    fn at(&self, t: T) -> Vec3<T> {
+        self.origin.add_v(&self.direction.mul_t(t))
+    }
+}
+
+/**
+ * Create the ray through a point in the window, in world space
+ *
+ * This works for both perspective and orthographic projections, including
+ * those with the far plane at infinity.
+ *
+ * # Arguments
+ *
+ * * `win`      - the window coordinates, as for `projection::unproject`. Add
+ *                `0.5` to the coordinates of a pixel to use its centre.
+ * * `view`     - the view matrix
+ * * `proj`     - the projection matrix
+ * * `viewport` - the viewport as `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * * `Some(ray)` - the ray, starting on the near plane
+ * * `None` - if `proj * view` is not invertible
+ */
+pub fn pick_ray<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(win: &Vec2<T>, view: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Ray<T>> {
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    match proj.mul_m(view).inverse() {
+        Some(inv) => {
+            let x = _2 * (win.x - viewport.x) / viewport.z - _1;
+            let y = _2 * (win.y - viewport.y) / viewport.w - _1;
+
+            let near  = inv.mul_v(&BaseVec4::new(x, y, -_1, _1));
+            let depth = inv.mul_v(&BaseVec4::new(zero(), zero(), _1, zero()));
+
+            let origin: Option<Vec3<T>> = FromHomogeneous::from_homogeneous_checked(&near);
+
+            match origin {
+                Some(origin) => {
+                    // the derivative of the unprojected point with respect to
+                    // the depth, scaled by `near.w²`. This points towards the
+                    // far plane whatever the sign of `w`, and is still defined
+                    // if the far plane is at infinity.
+                    let direction: Vec3<T> = BaseVec3::new(depth.x * near.w - near.x * depth.w,
+                                                           depth.y * near.w - near.y * depth.w,
+                                                           depth.z * near.w - near.z * depth.w);
+
+                    Some(Ray::new(origin, direction.normalize()))
+                }
+                None => None
+            }
+        }
+        None => None
+    }
+}
+
+/**
+ * Create the ray through a point in the window for a camera with a thin lens,
+ * which has a depth of field. Only the points on the plane of focus are
+ * sharp.
+ *
+ * The lens is centred on the camera, and parallel to the image plane. For a
+ * ray traced image, average many rays for each pixel, each with a different
+ * point on the lens.
+ *
+ * # Arguments
+ *
+ * * `win`            - the window coordinates, as for `pick_ray`
+ * * `view`           - the view matrix, which must not have any scale
+ * * `proj`           - the projection matrix
+ * * `viewport`       - the viewport as `(x, y, width, height)`
+ * * `aperture`       - the diameter of the lens
+ * * `focus_distance` - the distance of the plane of focus from the camera
+ * * `lens`           - the point on the lens that the ray passes through, on
+ *                      the unit disk. This can be created from a sample in the
+ *                      unit square with `concentric_disk`.
+ *
+ * # Return value
+ *
+ * * `Some(ray)` - the ray, starting on the lens
+ * * `None` - if `view` or `proj * view` is not invertible
+ */
+pub fn thin_lens_ray<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(win: &Vec2<T>, view: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>, aperture: T, focus_distance: T, lens: &Vec2<T>) -> Option<Ray<T>> {
+    let half: T = num::cast(0.5);
+
+    match (pick_ray(win, view, proj, viewport), view.inverse()) {
+        (Some(ray), Some(inv)) => {
+            let right:   Vec3<T> = BaseVec3::new(inv.x.x, inv.x.y, inv.x.z);
+            let up:      Vec3<T> = BaseVec3::new(inv.y.x, inv.y.y, inv.y.z);
+            let forward: Vec3<T> = BaseVec3::new(-inv.z.x, -inv.z.y, -inv.z.z);
+            let eye:     Vec3<T> = BaseVec3::new(inv.w.x, inv.w.y, inv.w.z);
+
+            // move the origin back to the plane of the camera, which is the
+            // eye itself for a perspective projection
+            let cos_theta = ray.direction.dot(&forward);
+            let t = -ray.origin.sub_v(&eye).dot(&forward) / cos_theta;
+
+            let centre = ray.at(t);
+            let focus = ray.at(t + focus_distance / cos_theta);
+            let origin = centre.add_v(&right.mul_t(lens.x * aperture * half))
+                               .add_v(&up.mul_t(lens.y * aperture * half));
+
+            Some(Ray::new(origin, focus.sub_v(&origin).normalize()))
+        }
+        _ => None
+    }
+}
+
+/**
+ * Map a point in the unit square to a point in the unit disk, preserving
+ * relative areas, for example to sample the lens of `thin_lens_ray`
+ *
+ * - [A Low Distortion Map Between Disk and Square]
+ *   (http://www.cs.utah.edu/~shirley/papers/concentric.pdf)
+ */
+pub fn concentric_disk<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(p: &Vec2<T>) -> Vec2<T> {
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    let frac_pi_2: T = Float::frac_pi_2();
+    let frac_pi_4: T = Float::frac_pi_4();
+
+    let a = _2 * p.x - _1;
+    let b = _2 * p.y - _1;
+
+    if a == zero() && b == zero() {
+        BaseVec2::new(zero(), zero())
+    } else {
+        let (r, phi) = if abs(a) > abs(b) {
+            (a, frac_pi_4 * (b / a))
+        } else {
+            (b, frac_pi_2 - frac_pi_4 * (a / b))
+        };
+        BaseVec2::new(r * cos(phi), r * sin(phi))
+    }
+}
+
+// Rust-style type aliases
+pub type FpsCameraf       = FpsCamera<float>;
+pub type FpsCameraf32     = FpsCamera<f32>;
+pub type FpsCameraf64     = FpsCamera<f64>;
+pub type OrbitCameraf     = OrbitCamera<float>;
+pub type OrbitCameraf32   = OrbitCamera<f32>;
+pub type OrbitCameraf64   = OrbitCamera<f64>;
+pub type ArcballCameraf   = ArcballCamera<float>;
+pub type ArcballCameraf32 = ArcballCamera<f32>;
+pub type ArcballCameraf64 = ArcballCamera<f64>;
+pub type Rayf             = Ray<float>;
+pub type Rayf32           = Ray<f32>;
+pub type Rayf64           = Ray<f64>;
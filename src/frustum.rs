@@ -0,0 +1,253 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+
+use mat::{BaseMat, Mat4};
+use vec::{Vec3, BaseVec3, Vec4, BaseVec4, AffineVec, NumVec, NumVec3};
+
+/**
+ * The result of testing a point or volume against a frustum
+ */
+#[deriving(Eq)]
+pub enum Containment {
+    /// entirely inside the frustum
+    Inside,
+    /// entirely outside the frustum
+    Outside,
+    /// partly inside and partly outside the frustum
+    Intersecting,
+}
+
+/**
+ * A view frustum, described by the six planes bounding it
+ *
+ * Each plane is stored as a vector `(a, b, c, d)` such that a point `p` is on
+ * the inner side of the plane when `a*p.x + b*p.y + c*p.z + d >= 0`. The
+ * normal `(a, b, c)` of each plane is normalized, so this is the distance of
+ * the point from the plane.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `left`, `right`, `bottom`, `top`, `near`, `far` - the bounding planes
+ */
+#[deriving(Eq)]
+pub struct Frustum<T> {
+    left:   Vec4<T>,
+    right:  Vec4<T>,
+    bottom: Vec4<T>,
+    top:    Vec4<T>,
+    near:   Vec4<T>,
+    far:    Vec4<T>,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Frustum<T> {
+    /**
+     * Extract the frustum from a projection or view-projection matrix
+     *
+     * The planes are extracted in the space that is the input of the matrix,
+     * so a projection matrix gives the frustum in eye space, and a
+     * view-projection matrix gives the frustum in world space. The matrix
+     * should follow the OpenGL conventions, as produced by `perspective`,
+     * `frustum` and `orthographic`.
+     *
+     * A plane that is at infinity, for example the far plane of
+     * `perspective_infinite`, is replaced by one that contains every point.
+     *
+     * - [Fast Extraction of Viewing Frustum Planes from the World-View-Projection Matrix]
+     *   (http://www.cs.otago.ac.nz/postgrads/alexis/planeExtraction.pdf)
+     */
+    fn from_mat4(m: &Mat4<T>) -> Frustum<T> {
+        let r0 = m.row(0);
+        let r1 = m.row(1);
+        let r2 = m.row(2);
+        let r3 = m.row(3);
+
+        Frustum { left:   normalize_plane(&r3.add_v(&r0)),
+                  right:  normalize_plane(&r3.sub_v(&r0)),
+                  bottom: normalize_plane(&r3.add_v(&r1)),
+                  top:    normalize_plane(&r3.sub_v(&r1)),
+                  near:   normalize_plane(&r3.add_v(&r2)),
+                  far:    normalize_plane(&r3.sub_v(&r2)) }
+    }
+
+    /**
+     * # Return value
+     *
+     * The planes in the order left, right, bottom, top, near, far
+     */
+    #[inline(always)]
+    fn planes(&self) -> [Vec4<T>, ..6] {
+        [self.left, self.right, self.bottom, self.top, self.near, self.far]
+    }
+
+    /**
+     * # Return value
+     *
+     * `Inside` if the point is inside the frustum or on its boundary, or
+     * `Outside` if it is not
+     */
+    fn contains_point(&self, point: &Vec3<T>) -> Containment {
+        for self.planes().each |plane| {
+            if plane_distance(plane, point) < zero() { return Outside; }
+        }
+        Inside
+    }
+
+    /**
+     * Test a sphere against the frustum
+     *
+     * # Arguments
+     *
+     * * `centre` - the centre of the sphere
+     * * `radius` - the radius of the sphere
+     */
+    fn contains_sphere(&self, centre: &Vec3<T>, radius: T) -> Containment {
+        let mut result = Inside;
+
+        for self.planes().each |plane| {
+            let distance = plane_distance(plane, centre);
+            if distance < -radius {
+                return Outside;
+            } else if distance < radius {
+                result = Intersecting;
+            }
+        }
+
+        result
+    }
+
+    /**
+     * Test an axis aligned bounding box against the frustum
+     *
+     * # Arguments
+     *
+     * * `min` - the corner of the box with the smallest coordinates
+     * * `max` - the corner of the box with the largest coordinates
+     *
+     * # Return value
+     *
+     * The containment of the box. As is usual for this test, a large box near
+     * a corner of the frustum may be reported as `Intersecting` even though it
+     * is outside.
+     */
+    fn contains_aabb(&self, min: &Vec3<T>, max: &Vec3<T>) -> Containment {
+        let mut result = Inside;
+
+        for self.planes().each |plane| {
+            // the corners of the box furthest along and against the normal
+            let p: Vec3<T> = BaseVec3::new(if plane.x >= zero() { max.x } else { min.x },
+                                           if plane.y >= zero() { max.y } else { min.y },
+                                           if plane.z >= zero() { max.z } else { min.z });
+            let n: Vec3<T> = BaseVec3::new(if plane.x >= zero() { min.x } else { max.x },
+                                           if plane.y >= zero() { min.y } else { max.y },
+                                           if plane.z >= zero() { min.z } else { max.z });
+
+            if plane_distance(plane, &p) < zero() {
+                return Outside;
+            } else if plane_distance(plane, &n) < zero() {
+                result = Intersecting;
+            }
+        }
+
+        result
+    }
+
+    /**
+     * # Return value
+     *
+     * The eight corners of the frustum, for example for debug drawing. The
+     * corner at index `i` is on the right if bit 0 of `i` is set, at the top
+     * if bit 1 is set and on the far plane if bit 2 is set, ie:
+     *
+     * ~~~
+     * 0: near bottom left     4: far bottom left
+     * 1: near bottom right    5: far bottom right
+     * 2: near top left        6: far top left
+     * 3: near top right       7: far top right
+     * ~~~
+     *
+     * The far corners are not defined if the far plane is at infinity.
+     */
+    fn corners(&self) -> [Vec3<T>, ..8] {
+        [intersect_planes(&self.near, &self.bottom, &self.left),
+         intersect_planes(&self.near, &self.bottom, &self.right),
+         intersect_planes(&self.near, &self.top,    &self.left),
+         intersect_planes(&self.near, &self.top,    &self.right),
+         intersect_planes(&self.far,  &self.bottom, &self.left),
+         intersect_planes(&self.far,  &self.bottom, &self.right),
+         intersect_planes(&self.far,  &self.top,    &self.left),
+         intersect_planes(&self.far,  &self.top,    &self.right)]
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T>> FuzzyEq<T> for Frustum<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Frustum<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Frustum<T>, epsilon: &T) -> bool {
+        self.left.fuzzy_eq_eps(&other.left, epsilon) &&
+        self.right.fuzzy_eq_eps(&other.right, epsilon) &&
+        self.bottom.fuzzy_eq_eps(&other.bottom, epsilon) &&
+        self.top.fuzzy_eq_eps(&other.top, epsilon) &&
+        self.near.fuzzy_eq_eps(&other.near, epsilon) &&
+        self.far.fuzzy_eq_eps(&other.far, epsilon)
+    }
+}
+
+/**
+ * Scale the plane so that its normal has unit length, or replace it with a
+ * plane containing every point if it has no normal
+ */
+#[inline(always)]
+fn normalize_plane<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(plane: &Vec4<T>) -> Vec4<T> {
+    let normal: Vec3<T> = BaseVec3::new(plane.x, plane.y, plane.z);
+    let length = normal.length();
+
+    if length.fuzzy_eq(&zero()) {
+        BaseVec4::new(zero(), zero(), zero(), one())
+    } else {
+        plane.div_t(length)
+    }
+}
+
+/**
+ * # Return value
+ *
+ * The signed distance of `point` from `plane`
+ */
+#[inline(always)]
+fn plane_distance<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(plane: &Vec4<T>, point: &Vec3<T>) -> T {
+    plane.x * point.x + plane.y * point.y + plane.z * point.z + plane.w
+}
+
+/**
+ * # Return value
+ *
+ * The point where the three planes meet
+ */
+#[inline(always)]
+fn intersect_planes<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(a: &Vec4<T>, b: &Vec4<T>, c: &Vec4<T>) -> Vec3<T> {
+    let na: Vec3<T> = BaseVec3::new(a.x, a.y, a.z);
+    let nb: Vec3<T> = BaseVec3::new(b.x, b.y, b.z);
+    let nc: Vec3<T> = BaseVec3::new(c.x, c.y, c.z);
+
+    let bc = nb.cross(&nc);
+    let ca = nc.cross(&na);
+    let ab = na.cross(&nb);
+
+    bc.mul_t(-a.w).add_v(&ca.mul_t(-b.w)).add_v(&ab.mul_t(-c.w)).div_t(na.dot(&bc))
+}
+
+// Rust-style type aliases
+pub type Frustumf   = Frustum<float>;
+pub type Frustumf32 = Frustum<f32>;
+pub type Frustumf64 = Frustum<f64>;
@@ -0,0 +1,311 @@
+use core::num::{Zero, One};
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::{BaseMat, Mat4};
+
+/**
+ * A node in a `Hierarchy`
+ *
+ * # Fields
+ *
+ * * `parent`   - the index of the parent node, or `None` for a root node
+ * * `children` - the indices of the child nodes
+ * * `local`    - the transformation relative to the parent node
+ * * `world`    - the cached transformation relative to the world
+ * * `dirty`    - `true` if `local` has changed since `world` was last computed
+ */
+struct Node<T> {
+    parent:   Option<uint>,
+    children: ~[uint],
+    local:    Mat4<T>,
+    world:    Mat4<T>,
+    dirty:    bool,
+}
+
+/**
+ * An arena-based hierarchy of transformations, such as the core of a scene
+ * graph
+ *
+ * Nodes are referred to by the index returned from `add`. Each node stores a
+ * transformation relative to its parent, and `update` propagates these down
+ * the hierarchy to compute the transformation of each node relative to the
+ * world, ie: `world = parent.world * local`. Only the nodes that have
+ * changed, and their descendants, are recomputed.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the elements of the matrices. Should be a floating
+ *         point type.
+ */
+pub struct Hierarchy<T> {
+    priv nodes: ~[Node<T>],
+    priv order: ~[uint],
+    priv order_dirty: bool,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Hierarchy<T> {
+    /**
+     * Construct an empty hierarchy
+     */
+    #[inline(always)]
+    fn new() -> Hierarchy<T> {
+        Hierarchy { nodes: ~[], order: ~[], order_dirty: false }
+    }
+
+    /**
+     * # Return value
+     *
+     * The number of nodes in the hierarchy
+     */
+    #[inline(always)]
+    fn len(&self) -> uint {
+        self.nodes.len()
+    }
+
+    /**
+     * Add a node to the hierarchy
+     *
+     * # Arguments
+     *
+     * * `local`  - the transformation relative to the parent
+     * * `parent` - the index of the parent node, or `None` for a root node
+     *
+     * # Return value
+     *
+     * The index of the new node
+     */
+    fn add(&mut self, local: Mat4<T>, parent: Option<uint>) -> uint {
+        let id = self.nodes.len();
+
+        match parent {
+            Some(p) => {
+                self.check_index(p);
+                self.nodes[p].children.push(id);
+            }
+            None => ()
+        }
+
+        self.nodes.push(Node { parent: parent,
+                               children: ~[],
+                               local: local,
+                               world: BaseMat::identity(),
+                               dirty: true });
+        self.order_dirty = true;
+
+        id
+    }
+
+    /**
+     * # Return value
+     *
+     * The index of the parent of the node at `id`, or `None` if it is a root
+     * node
+     */
+    #[inline(always)]
+    fn parent(&self, id: uint) -> Option<uint> {
+        self.check_index(id);
+        self.nodes[id].parent
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation of the node at `id` relative to its parent
+     */
+    #[inline(always)]
+    fn local(&self, id: uint) -> Mat4<T> {
+        self.check_index(id);
+        self.nodes[id].local
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation of the node at `id` relative to the world, as of the
+     * last call to `update`
+     */
+    #[inline(always)]
+    fn world(&self, id: uint) -> Mat4<T> {
+        self.check_index(id);
+        self.nodes[id].world
+    }
+
+    /**
+     * # Return value
+     *
+     * `true` if the local transformation of the node at `id` has changed since
+     * the last call to `update`
+     */
+    #[inline(always)]
+    fn is_dirty(&self, id: uint) -> bool {
+        self.check_index(id);
+        self.nodes[id].dirty
+    }
+
+    /**
+     * Set the transformation of the node at `id` relative to its parent
+     */
+    #[inline(always)]
+    fn set_local(&mut self, id: uint, local: Mat4<T>) {
+        self.check_index(id);
+        self.nodes[id].local = local;
+        self.nodes[id].dirty = true;
+    }
+
+    /**
+     * Move the node at `id` to a new parent, adjusting its local
+     * transformation so that its transformation relative to the world is
+     * preserved
+     *
+     * # Return value
+     *
+     * * `Ok(())` - if the node was moved
+     * * `Err(msg)` - if the node would become its own ancestor, or the world
+     *   transformation of the new parent is not invertible. The hierarchy is
+     *   left unchanged.
+     */
+    fn set_parent(&mut self, id: uint, parent: Option<uint>) -> Result<(), ~str> {
+        self.check_index(id);
+
+        match parent {
+            Some(p) => {
+                self.check_index(p);
+                if self.is_ancestor(id, p) {
+                    return Err(fmt!("node %u can not be a child of its descendant %u", id, p));
+                }
+            }
+            None => ()
+        }
+
+        let world = self.current_world(id);
+        let local = match parent {
+            Some(p) => match self.current_world(p).inverse() {
+                Some(inv) => inv.mul_m(&world),
+                None => return Err(fmt!("the world transformation of node %u is not invertible", p))
+            },
+            None => world
+        };
+
+        match self.nodes[id].parent {
+            Some(old) => {
+                let children = vec::filter(self.nodes[old].children, |&c| c != id);
+                self.nodes[old].children = children;
+            }
+            None => ()
+        }
+
+        match parent {
+            Some(p) => self.nodes[p].children.push(id),
+            None => ()
+        }
+
+        self.nodes[id].parent = parent;
+        self.nodes[id].local = local;
+        self.nodes[id].dirty = true;
+        self.order_dirty = true;
+
+        Ok(())
+    }
+
+    /**
+     * # Return value
+     *
+     * `true` if the node at `ancestor` is the node at `id` or one of its
+     * ancestors
+     */
+    fn is_ancestor(&self, ancestor: uint, id: uint) -> bool {
+        let mut node = Some(id);
+        loop {
+            match node {
+                Some(n) if n == ancestor => return true,
+                Some(n) => node = self.nodes[n].parent,
+                None => return false
+            }
+        }
+    }
+
+    /**
+     * Recompute the world transformations of the nodes that have changed, and
+     * of their descendants. Parents are always updated before their children.
+     */
+    fn update(&mut self) {
+        if self.order_dirty { self.rebuild_order(); }
+
+        let mut changed = vec::from_elem(self.nodes.len(), false);
+
+        for uint::range(0, self.order.len()) |i| {
+            let id = self.order[i];
+            let parent = self.nodes[id].parent;
+
+            let parent_changed = match parent {
+                Some(p) => changed[p],
+                None => false
+            };
+
+            if self.nodes[id].dirty || parent_changed {
+                let world = match parent {
+                    Some(p) => self.nodes[p].world.mul_m(&self.nodes[id].local),
+                    None => self.nodes[id].local
+                };
+
+                self.nodes[id].world = world;
+                self.nodes[id].dirty = false;
+                changed[id] = true;
+            }
+        }
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation of the node at `id` relative to the world, computed
+     * from the local transformations without touching the cached ones
+     */
+    priv fn current_world(&self, id: uint) -> Mat4<T> {
+        let mut world = self.nodes[id].local;
+        let mut node = self.nodes[id].parent;
+        loop {
+            match node {
+                Some(n) => {
+                    world = self.nodes[n].local.mul_m(&world);
+                    node = self.nodes[n].parent;
+                }
+                None => return world
+            }
+        }
+    }
+
+    /**
+     * Sort the nodes so that each parent comes before its children
+     */
+    priv fn rebuild_order(&mut self) {
+        let mut order = ~[];
+
+        for uint::range(0, self.nodes.len()) |id| {
+            if self.nodes[id].parent.is_none() { order.push(id); }
+        }
+
+        let mut i = 0;
+        while i < order.len() {
+            let id = order[i];
+            for self.nodes[id].children.each |&c| { order.push(c); }
+            i += 1;
+        }
+
+        self.order = order;
+        self.order_dirty = false;
+    }
+
+    #[inline(always)]
+    priv fn check_index(&self, id: uint) {
+        if id >= self.nodes.len() {
+            fail!(fmt!("index out of bounds: the hierarchy has %u nodes, but found %u", self.nodes.len(), id))
+        }
+    }
+}
+
+// Rust-style type aliases
+pub type Hierarchyf   = Hierarchy<float>;
+pub type Hierarchyf32 = Hierarchy<f32>;
+pub type Hierarchyf64 = Hierarchy<f64>;
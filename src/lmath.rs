@@ -11,15 +11,34 @@
 extern mod std;
 extern mod numeric;
 
+pub mod angle;
+pub mod camera;
+pub mod frustum;
+pub mod hierarchy;
 pub mod mat;
+pub mod point;
 pub mod quat;
+pub mod rot;
+pub mod shadow;
+pub mod stack;
+pub mod transform;
 pub mod vec;
 
 pub mod projection;
 
 #[test]
 mod test {
+    #[path = "test_angle.rs"] mod angle;
+    #[path = "test_camera.rs"] mod camera;
+    #[path = "test_frustum.rs"] mod frustum;
+    #[path = "test_hierarchy.rs"] mod hierarchy;
     #[path = "test_mat.rs" ] mod mat;
+    #[path = "test_point.rs"] mod point;
+    #[path = "test_projection.rs"] mod projection;
     #[path = "test_quat.rs"] mod quat;
+    #[path = "test_rot.rs" ] mod rot;
+    #[path = "test_shadow.rs"] mod shadow;
+    #[path = "test_stack.rs"] mod stack;
+    #[path = "test_transform.rs"] mod transform;
     #[path = "test_vec.rs" ] mod vec;
 }
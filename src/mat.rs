@@ -4,6 +4,8 @@ use core::num::One::one;
 use std::cmp::{FuzzyEq, FUZZY_EPSILON};
 use numeric::*;
 
+use angle::{Angle, Rad, Deg};
+use point::Point3;
 use vec::*;
 use quat::Quat;
 
@@ -251,6 +253,10 @@ pub trait BaseMat2<T,V>: BaseMat<T,V> {
 
     fn from_angle(radians: T) -> Self;
 
+    fn from_rad(angle: Rad<T>) -> Self;
+
+    fn from_deg(angle: Deg<T>) -> Self;
+
     fn to_mat3(&self) -> Mat3<T>;
 
     fn to_mat4(&self) -> Mat4<T>;
@@ -276,6 +282,26 @@ pub trait BaseMat3<T,V>: BaseMat<T,V> {
 
     fn from_angle_axis(radians: T, axis: &Vec3<T>) -> Self;
 
+    fn from_rad_x(angle: Rad<T>) -> Self;
+
+    fn from_rad_y(angle: Rad<T>) -> Self;
+
+    fn from_rad_z(angle: Rad<T>) -> Self;
+
+    fn from_rad_xyz(x: Rad<T>, y: Rad<T>, z: Rad<T>) -> Self;
+
+    fn from_rad_axis(angle: Rad<T>, axis: &Vec3<T>) -> Self;
+
+    fn from_deg_x(angle: Deg<T>) -> Self;
+
+    fn from_deg_y(angle: Deg<T>) -> Self;
+
+    fn from_deg_z(angle: Deg<T>) -> Self;
+
+    fn from_deg_xyz(x: Deg<T>, y: Deg<T>, z: Deg<T>) -> Self;
+
+    fn from_deg_axis(angle: Deg<T>, axis: &Vec3<T>) -> Self;
+
     fn from_axes(x: V, y: V, z: V) -> Self;
 
     fn look_at(dir: &Vec3<T>, up: &Vec3<T>) -> Self;
@@ -295,6 +321,14 @@ pub trait BaseMat4<T,V>: BaseMat<T,V> {
            c3r0: T, c3r1: T, c3r2: T, c3r3: T) -> Self;
 
     fn from_cols(c0: V, c1: V, c2: V, c3: V) -> Self;
+
+    fn to_mat3(&self) -> Mat3<T>;
+
+    fn to_quat(&self) -> Quat<T>;
+
+    fn transform_point(&self, point: &Point3<T>) -> Point3<T>;
+
+    fn transform_vector(&self, vec: &Vec3<T>) -> Vec3<T>;
 }
 
 /**
@@ -594,6 +628,24 @@ impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> +
                       sin_theta,  cos_theta)
     }
 
+    /**
+     * Construct a matrix from an angular rotation, with the angle typed as
+     * radians
+     */
+    #[inline(always)]
+    fn from_rad(angle: Rad<T>) -> Mat2<T> {
+        BaseMat2::from_angle(*angle)
+    }
+
+    /**
+     * Construct a matrix from an angular rotation, with the angle typed as
+     * degrees
+     */
+    #[inline(always)]
+    fn from_deg(angle: Deg<T>) -> Mat2<T> {
+        BaseMat2::from_rad(angle.to_rad())
+    }
+
     /**
      * Returns the the matrix with an extra row and column added
      * ~~~
@@ -678,6 +730,8 @@ macro_rules! mat2_type(
             #[inline(always)] fn zero() -> $name { BaseMat::zero() }
 
             #[inline(always)] fn from_angle(radians: $T) -> $name { BaseMat2::from_angle(radians) }
+            #[inline(always)] fn from_rad(angle: Rad<$T>) -> $name { BaseMat2::from_rad(angle) }
+            #[inline(always)] fn from_deg(angle: Deg<$T>) -> $name { BaseMat2::from_deg(angle) }
 
             #[inline(always)] fn dim() -> uint { 2 }
             #[inline(always)] fn rows() -> uint { 2 }
@@ -1136,6 +1190,94 @@ impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> +
                     _1_c*x*z + s*y, _1_c*y*z - s*x, _1_c*z*z + c)
     }
 
+    /**
+     * Construct a matrix from an angular rotation around the `x` axis, with
+     * the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_x(angle: Rad<T>) -> Mat3<T> {
+        BaseMat3::from_angle_x(*angle)
+    }
+
+    /**
+     * Construct a matrix from an angular rotation around the `y` axis, with
+     * the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_y(angle: Rad<T>) -> Mat3<T> {
+        BaseMat3::from_angle_y(*angle)
+    }
+
+    /**
+     * Construct a matrix from an angular rotation around the `z` axis, with
+     * the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_z(angle: Rad<T>) -> Mat3<T> {
+        BaseMat3::from_angle_z(*angle)
+    }
+
+    /**
+     * Construct a matrix from Euler angles typed as radians, as for
+     * `from_angle_xyz`
+     */
+    #[inline(always)]
+    fn from_rad_xyz(x: Rad<T>, y: Rad<T>, z: Rad<T>) -> Mat3<T> {
+        BaseMat3::from_angle_xyz(*x, *y, *z)
+    }
+
+    /**
+     * Construct a matrix from an axis and an angular rotation typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_axis(angle: Rad<T>, axis: &Vec3<T>) -> Mat3<T> {
+        BaseMat3::from_angle_axis(*angle, axis)
+    }
+
+    /**
+     * Construct a matrix from an angular rotation around the `x` axis, with
+     * the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_x(angle: Deg<T>) -> Mat3<T> {
+        BaseMat3::from_rad_x(angle.to_rad())
+    }
+
+    /**
+     * Construct a matrix from an angular rotation around the `y` axis, with
+     * the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_y(angle: Deg<T>) -> Mat3<T> {
+        BaseMat3::from_rad_y(angle.to_rad())
+    }
+
+    /**
+     * Construct a matrix from an angular rotation around the `z` axis, with
+     * the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_z(angle: Deg<T>) -> Mat3<T> {
+        BaseMat3::from_rad_z(angle.to_rad())
+    }
+
+    /**
+     * Construct a matrix from Euler angles typed as degrees, as for
+     * `from_angle_xyz`
+     */
+    #[inline(always)]
+    fn from_deg_xyz(x: Deg<T>, y: Deg<T>, z: Deg<T>) -> Mat3<T> {
+        BaseMat3::from_rad_xyz(x.to_rad(), y.to_rad(), z.to_rad())
+    }
+
+    /**
+     * Construct a matrix from an axis and an angular rotation typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_axis(angle: Deg<T>, axis: &Vec3<T>) -> Mat3<T> {
+        BaseMat3::from_rad_axis(angle.to_rad(), axis)
+    }
+
     #[inline(always)]
     fn from_axes(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Mat3<T> {
         BaseMat3::from_cols(x, y, z)
@@ -1175,6 +1317,10 @@ impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> +
 
     /**
      * Convert the matrix to a quaternion
+     *
+     * The result is normalized, so matrices that have drifted slightly from
+     * being orthonormal, for example after accumulating many rotations, still
+     * produce a unit quaternion.
      */
     #[inline(always)]
     fn to_quat(&self) -> Quat<T> {
@@ -1196,29 +1342,29 @@ impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> +
             y = (self[2][0] - self[0][2]) * s;
             z = (self[0][1] - self[1][0]) * s;
         } else if (self[0][0] > self[1][1]) && (self[0][0] > self[2][2]) {
-            s = (half + (self[0][0] - self[1][1] - self[2][2])).sqrt();
-            w = half * s;
+            s = (_1 + (self[0][0] - self[1][1] - self[2][2])).sqrt();
+            x = half * s;
             s = half / s;
-            x = (self[0][1] - self[1][0]) * s;
-            y = (self[2][0] - self[0][2]) * s;
-            z = (self[1][2] - self[2][1]) * s;
+            w = (self[1][2] - self[2][1]) * s;
+            y = (self[1][0] + self[0][1]) * s;
+            z = (self[2][0] + self[0][2]) * s;
         } else if self[1][1] > self[2][2] {
-            s = (half + (self[1][1] - self[0][0] - self[2][2])).sqrt();
-            w = half * s;
+            s = (_1 + (self[1][1] - self[0][0] - self[2][2])).sqrt();
+            y = half * s;
             s = half / s;
-            x = (self[0][1] - self[1][0]) * s;
-            y = (self[1][2] - self[2][1]) * s;
-            z = (self[2][0] - self[0][2]) * s;
+            w = (self[2][0] - self[0][2]) * s;
+            x = (self[1][0] + self[0][1]) * s;
+            z = (self[2][1] + self[1][2]) * s;
         } else {
-            s = (half + (self[2][2] - self[0][0] - self[1][1])).sqrt();
-            w = half * s;
+            s = (_1 + (self[2][2] - self[0][0] - self[1][1])).sqrt();
+            z = half * s;
             s = half / s;
-            x = (self[2][0] - self[0][2]) * s;
-            y = (self[1][2] - self[2][1]) * s;
-            z = (self[0][1] - self[1][0]) * s;
+            w = (self[0][1] - self[1][0]) * s;
+            x = (self[2][0] + self[0][2]) * s;
+            y = (self[2][1] + self[1][2]) * s;
         }
 
-        Quat::new(w, x, y, z)
+        Quat::new(w, x, y, z).normalize()
     }
 }
 
@@ -1267,6 +1413,16 @@ macro_rules! mat3_type(
             #[inline(always)] fn from_angle_z(radians: $T) -> $name { BaseMat3::from_angle_z(radians) }
             #[inline(always)] fn from_angle_xyz(radians_x: $T, radians_y: $T, radians_z: $T) -> $name { BaseMat3::from_angle_xyz(radians_x, radians_y, radians_z) }
             #[inline(always)] fn from_angle_axis(radians: $T, axis: &$V) -> $name { BaseMat3::from_angle_axis(radians, axis) }
+            #[inline(always)] fn from_rad_x(angle: Rad<$T>) -> $name { BaseMat3::from_rad_x(angle) }
+            #[inline(always)] fn from_rad_y(angle: Rad<$T>) -> $name { BaseMat3::from_rad_y(angle) }
+            #[inline(always)] fn from_rad_z(angle: Rad<$T>) -> $name { BaseMat3::from_rad_z(angle) }
+            #[inline(always)] fn from_rad_xyz(x: Rad<$T>, y: Rad<$T>, z: Rad<$T>) -> $name { BaseMat3::from_rad_xyz(x, y, z) }
+            #[inline(always)] fn from_rad_axis(angle: Rad<$T>, axis: &$V) -> $name { BaseMat3::from_rad_axis(angle, axis) }
+            #[inline(always)] fn from_deg_x(angle: Deg<$T>) -> $name { BaseMat3::from_deg_x(angle) }
+            #[inline(always)] fn from_deg_y(angle: Deg<$T>) -> $name { BaseMat3::from_deg_y(angle) }
+            #[inline(always)] fn from_deg_z(angle: Deg<$T>) -> $name { BaseMat3::from_deg_z(angle) }
+            #[inline(always)] fn from_deg_xyz(x: Deg<$T>, y: Deg<$T>, z: Deg<$T>) -> $name { BaseMat3::from_deg_xyz(x, y, z) }
+            #[inline(always)] fn from_deg_axis(angle: Deg<$T>, axis: &$V) -> $name { BaseMat3::from_deg_axis(angle, axis) }
             #[inline(always)] fn from_axes(x: $V, y: $V, z: $V) -> $name { BaseMat3::from_axes(x, y, z) }
             #[inline(always)] fn look_at(dir: &$V, up: &$V) -> $name { BaseMat3::look_at(dir, up) }
 
@@ -1747,6 +1903,70 @@ impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> +
     fn from_cols(c0: Vec4<T>, c1: Vec4<T>, c2: Vec4<T>, c3: Vec4<T>) -> Mat4<T> {
         Mat4 { x: c0, y: c1, z: c2, w: c3 }
     }
+
+    /**
+     * Returns the the upper-left 3 x 3 matrix
+     * ~~~
+     *       c0   c1   c2   c3                 c0   c1   c2
+     *     +----+----+----+----+             +----+----+----+
+     *  r0 |  a |  b |  c |  d |          r0 |  a |  b |  c |
+     *     +----+----+----+----+             +----+----+----+
+     *  r1 |  e |  f |  g |  h |    =>    r1 |  e |  f |  g |
+     *     +----+----+----+----+             +----+----+----+
+     *  r2 |  i |  j |  k |  l |          r2 |  i |  j |  k |
+     *     +----+----+----+----+             +----+----+----+
+     *  r3 |  m |  n |  o |  p |
+     *     +----+----+----+----+
+     * ~~~
+     */
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> {
+        BaseMat3::new(self[0][0], self[0][1], self[0][2],
+                      self[1][0], self[1][1], self[1][2],
+                      self[2][0], self[2][1], self[2][2])
+    }
+
+    /**
+     * Convert the rotation of the matrix to a quaternion
+     *
+     * The upper-left 3 x 3 matrix is orthonormalized with the Gram-Schmidt
+     * process first, so any scale or shear in the matrix is ignored and the
+     * translation is discarded.
+     */
+    #[inline(always)]
+    fn to_quat(&self) -> Quat<T> {
+        let m = self.to_mat3();
+
+        let x = m[0].normalize();
+        let y = m[1].sub_v(&x.mul_t(x.dot(&m[1]))).normalize();
+        let z = x.cross(&y);
+
+        let r: Mat3<T> = BaseMat3::from_cols(x, y, z);
+        r.to_quat()
+    }
+
+    /**
+     * Transform a point, including the translation of the matrix
+     *
+     * The point is treated as having a `w` of one. If the matrix is a
+     * projection the result is divided by the transformed `w`.
+     */
+    #[inline(always)]
+    fn transform_point(&self, point: &Point3<T>) -> Point3<T> {
+        FromHomogeneous::from_homogeneous(&self.mul_v(&point.to_homogeneous()))
+    }
+
+    /**
+     * Transform a vector, ignoring the translation of the matrix
+     *
+     * The vector is treated as having a `w` of zero, so only the upper-left
+     * 3 x 3 matrix affects it.
+     */
+    #[inline(always)]
+    fn transform_vector(&self, vec: &Vec3<T>) -> Vec3<T> {
+        let v = self.mul_v(&vec.to_homogeneous());
+        BaseVec3::new(v.x, v.y, v.z)
+    }
 }
 
 impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Neg<Mat4<T>> for Mat4<T> {
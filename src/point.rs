@@ -0,0 +1,304 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+
+use vec::{Vec2, BaseVec2, Vec3, BaseVec3, Vec4, BaseVec4, AffineVec, ToHomogeneous, FromHomogeneous};
+
+/**
+ * A position in an affine space
+ *
+ * Unlike a vector, a point has no length or direction. The difference of two
+ * points is a vector, and adding a vector to a point gives another point.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. This should be a floating point type.
+ * * `V` - The type of the vectors between the points
+ */
+pub trait Point<T, V>: Eq {
+    /**
+     * # Return value
+     *
+     * The point with all of its components set to zero
+     */
+    fn origin() -> Self;
+
+    /**
+     * Construct the point at the end of `vec`, starting from the origin
+     */
+    fn from_vec(vec: &V) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The vector from the origin to the point
+     */
+    fn to_vec(&self) -> V;
+
+    /**
+     * # Return value
+     *
+     * The point translated by `vec`
+     */
+    fn add_v(&self, vec: &V) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The point translated by the negation of `vec`
+     */
+    fn sub_v(&self, vec: &V) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The vector from `other` to the point
+     */
+    fn sub_p(&self, other: &Self) -> V;
+
+    /**
+     * # Return value
+     *
+     * The squared distance between the points
+     */
+    fn distance2(&self, other: &Self) -> T;
+
+    /**
+     * # Return value
+     *
+     * The distance between the points
+     */
+    fn distance(&self, other: &Self) -> T;
+}
+
+/**
+ * A 2-dimensional point
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. This should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `x` - the first component of the point
+ * * `y` - the second component of the point
+ */
+#[deriving(Eq)]
+pub struct Point2<T> { x: T, y: T }
+
+pub impl<T> Point2<T> {
+    #[inline(always)]
+    fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x: x, y: y }
+    }
+}
+
+impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Point<T, Vec2<T>> for Point2<T> {
+    #[inline(always)]
+    fn origin() -> Point2<T> {
+        Point2::new(zero(), zero())
+    }
+
+    #[inline(always)]
+    fn from_vec(vec: &Vec2<T>) -> Point2<T> {
+        Point2::new(vec.x, vec.y)
+    }
+
+    #[inline(always)]
+    fn to_vec(&self) -> Vec2<T> {
+        BaseVec2::new(self.x, self.y)
+    }
+
+    #[inline(always)]
+    fn add_v(&self, vec: &Vec2<T>) -> Point2<T> {
+        Point2::new(self.x + vec.x, self.y + vec.y)
+    }
+
+    #[inline(always)]
+    fn sub_v(&self, vec: &Vec2<T>) -> Point2<T> {
+        Point2::new(self.x - vec.x, self.y - vec.y)
+    }
+
+    #[inline(always)]
+    fn sub_p(&self, other: &Point2<T>) -> Vec2<T> {
+        BaseVec2::new(self.x - other.x, self.y - other.y)
+    }
+
+    #[inline(always)]
+    fn distance2(&self, other: &Point2<T>) -> T {
+        self.sub_p(other).length2()
+    }
+
+    #[inline(always)]
+    fn distance(&self, other: &Point2<T>) -> T {
+        self.sub_p(other).length()
+    }
+}
+
+impl<T:Copy + Number + Zero + One> ToHomogeneous<Vec3<T>> for Point2<T> {
+    #[inline(always)]
+    fn to_homogeneous(&self) -> Vec3<T> {
+        BaseVec3::new(self.x, self.y, one())
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FromHomogeneous<T, Vec3<T>> for Point2<T> {
+    #[inline(always)]
+    fn from_homogeneous(h: &Vec3<T>) -> Point2<T> {
+        let v: Vec2<T> = FromHomogeneous::from_homogeneous(h);
+        Point::from_vec(&v)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked(h: &Vec3<T>) -> Option<Point2<T>> {
+        let v: Option<Vec2<T>> = FromHomogeneous::from_homogeneous_checked(h);
+        v.map(|v| Point::from_vec(v))
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_with_w(h: &Vec3<T>) -> (Point2<T>, T) {
+        let (v, w): (Vec2<T>, T) = FromHomogeneous::from_homogeneous_with_w(h);
+        (Point::from_vec(&v), w)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked_with_w(h: &Vec3<T>) -> Option<(Point2<T>, T)> {
+        let v: Option<(Vec2<T>, T)> = FromHomogeneous::from_homogeneous_checked_with_w(h);
+        v.map(|&(v, w)| (Point::from_vec(&v), w))
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T>> FuzzyEq<T> for Point2<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Point2<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Point2<T>, epsilon: &T) -> bool {
+        self.x.fuzzy_eq_eps(&other.x, epsilon) &&
+        self.y.fuzzy_eq_eps(&other.y, epsilon)
+    }
+}
+
+/**
+ * A 3-dimensional point
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. This should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `x` - the first component of the point
+ * * `y` - the second component of the point
+ * * `z` - the third component of the point
+ */
+#[deriving(Eq)]
+pub struct Point3<T> { x: T, y: T, z: T }
+
+pub impl<T> Point3<T> {
+    #[inline(always)]
+    fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x: x, y: y, z: z }
+    }
+}
+
+impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Point<T, Vec3<T>> for Point3<T> {
+    #[inline(always)]
+    fn origin() -> Point3<T> {
+        Point3::new(zero(), zero(), zero())
+    }
+
+    #[inline(always)]
+    fn from_vec(vec: &Vec3<T>) -> Point3<T> {
+        Point3::new(vec.x, vec.y, vec.z)
+    }
+
+    #[inline(always)]
+    fn to_vec(&self) -> Vec3<T> {
+        BaseVec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline(always)]
+    fn add_v(&self, vec: &Vec3<T>) -> Point3<T> {
+        Point3::new(self.x + vec.x, self.y + vec.y, self.z + vec.z)
+    }
+
+    #[inline(always)]
+    fn sub_v(&self, vec: &Vec3<T>) -> Point3<T> {
+        Point3::new(self.x - vec.x, self.y - vec.y, self.z - vec.z)
+    }
+
+    #[inline(always)]
+    fn sub_p(&self, other: &Point3<T>) -> Vec3<T> {
+        BaseVec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+
+    #[inline(always)]
+    fn distance2(&self, other: &Point3<T>) -> T {
+        self.sub_p(other).length2()
+    }
+
+    #[inline(always)]
+    fn distance(&self, other: &Point3<T>) -> T {
+        self.sub_p(other).length()
+    }
+}
+
+impl<T:Copy + Number + Zero + One> ToHomogeneous<Vec4<T>> for Point3<T> {
+    #[inline(always)]
+    fn to_homogeneous(&self) -> Vec4<T> {
+        BaseVec4::new(self.x, self.y, self.z, one())
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FromHomogeneous<T, Vec4<T>> for Point3<T> {
+    #[inline(always)]
+    fn from_homogeneous(h: &Vec4<T>) -> Point3<T> {
+        let v: Vec3<T> = FromHomogeneous::from_homogeneous(h);
+        Point::from_vec(&v)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked(h: &Vec4<T>) -> Option<Point3<T>> {
+        let v: Option<Vec3<T>> = FromHomogeneous::from_homogeneous_checked(h);
+        v.map(|v| Point::from_vec(v))
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_with_w(h: &Vec4<T>) -> (Point3<T>, T) {
+        let (v, w): (Vec3<T>, T) = FromHomogeneous::from_homogeneous_with_w(h);
+        (Point::from_vec(&v), w)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked_with_w(h: &Vec4<T>) -> Option<(Point3<T>, T)> {
+        let v: Option<(Vec3<T>, T)> = FromHomogeneous::from_homogeneous_checked_with_w(h);
+        v.map(|&(v, w)| (Point::from_vec(&v), w))
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T>> FuzzyEq<T> for Point3<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Point3<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Point3<T>, epsilon: &T) -> bool {
+        self.x.fuzzy_eq_eps(&other.x, epsilon) &&
+        self.y.fuzzy_eq_eps(&other.y, epsilon) &&
+        self.z.fuzzy_eq_eps(&other.z, epsilon)
+    }
+}
+
+// Rust-style type aliases
+pub type Point2f   = Point2<float>;
+pub type Point2f32 = Point2<f32>;
+pub type Point2f64 = Point2<f64>;
+pub type Point3f   = Point3<float>;
+pub type Point3f32 = Point3<f32>;
+pub type Point3f64 = Point3<f64>;
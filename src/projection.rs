@@ -3,7 +3,96 @@ use numeric::*;
 
 use std::cmp::FuzzyEq;
 
-use mat::{Mat4, BaseMat4};
+use angle::{Angle, Rad, Deg};
+use mat::{Mat4, BaseMat4, BaseMat};
+use vec::{Vec2, BaseVec2, Vec3, BaseVec3, Vec4, BaseVec4, AffineVec, NumVec, NumVec3, FromHomogeneous};
+
+/**
+ * The orientation of the eye space coordinate system
+ */
+#[deriving(Eq)]
+pub enum Handedness {
+    /// the camera looks down the negative `z` axis, as in OpenGL
+    RightHanded,
+    /// the camera looks down the positive `z` axis, as in Direct3D
+    LeftHanded,
+}
+
+/**
+ * The range of depths in normalized device coordinates
+ */
+#[deriving(Eq)]
+pub enum DepthRange {
+    /// the near plane is mapped to `-1` and the far plane to `1`
+    NegativeOneToOne,
+    /// the near plane is mapped to `0` and the far plane to `1`
+    ZeroToOne,
+}
+
+/**
+ * The direction of the `y` axis in normalized device coordinates
+ */
+#[deriving(Eq)]
+pub enum YAxis {
+    /// `y = 1` is at the top of the viewport
+    YUp,
+    /// `y = 1` is at the bottom of the viewport
+    YDown,
+}
+
+/**
+ * The conventions used by a graphics API for eye space and clip space
+ *
+ * # Fields
+ *
+ * * `handedness` - the orientation of eye space
+ * * `depth`      - the range of depths in normalized device coordinates
+ * * `y`          - the direction of the `y` axis in normalized device
+ *                  coordinates
+ */
+#[deriving(Eq)]
+pub struct ClipSpace { handedness: Handedness, depth: DepthRange, y: YAxis }
+
+pub impl ClipSpace {
+    #[inline(always)]
+    fn new(handedness: Handedness, depth: DepthRange, y: YAxis) -> ClipSpace {
+        ClipSpace { handedness: handedness, depth: depth, y: y }
+    }
+
+    /**
+     * The OpenGL conventions, which are assumed by `perspective`, `frustum`
+     * and `orthographic`
+     */
+    #[inline(always)]
+    fn opengl() -> ClipSpace {
+        ClipSpace::new(RightHanded, NegativeOneToOne, YUp)
+    }
+
+    /**
+     * The Vulkan conventions, with a right handed eye space as in OpenGL
+     */
+    #[inline(always)]
+    fn vulkan() -> ClipSpace {
+        ClipSpace::new(RightHanded, ZeroToOne, YDown)
+    }
+
+    /**
+     * The Direct3D conventions, with a left handed eye space as used by the
+     * `D3DXMatrixPerspectiveLH` family of functions
+     */
+    #[inline(always)]
+    fn direct3d() -> ClipSpace {
+        ClipSpace::new(LeftHanded, ZeroToOne, YUp)
+    }
+
+    /**
+     * The Metal conventions, with a right handed eye space as in OpenGL
+     */
+    #[inline(always)]
+    fn metal() -> ClipSpace {
+        ClipSpace::new(RightHanded, ZeroToOne, YUp)
+    }
+}
 
 /**
  * Create a perspective projection matrix
@@ -23,6 +112,33 @@ pub fn perspective<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T
     frustum(-xmax, xmax, -ymax, ymax, near, far)
 }
 
+/**
+ * Create a perspective projection matrix, with the field of view typed as
+ * radians
+ *
+ * This is otherwise the same as `perspective`.
+ */
+#[inline(always)]
+pub fn perspective_rad<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: Rad<T>, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(*fovy / _2);
+    let xmax = ymax * aspectRatio;
+
+    frustum(-xmax, xmax, -ymax, ymax, near, far)
+}
+
+/**
+ * Create a perspective projection matrix, with the field of view typed as
+ * degrees
+ *
+ * This is otherwise the same as `perspective`.
+ */
+#[inline(always)]
+pub fn perspective_deg<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: Deg<T>, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    perspective_rad(fovy.to_rad(), aspectRatio, near, far)
+}
+
 /**
  * Define a view frustrum
  *
@@ -79,3 +195,1020 @@ pub fn orthographic<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,
                   -(right + left) / (right - left), -(top + bottom) / (top - bottom),
                     -(far + near) / (far - near), _1)
 }
+
+/**
+ * Create a perspective projection matrix with the far plane at infinity
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * Points at the near plane are mapped to a depth of `-1`, and points
+ * infinitely far away approach a depth of `1`.
+ */
+#[inline(always)]
+pub fn perspective_infinite<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T) -> Mat4<T> {
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(radians(fovy / _2));
+    let xmax = ymax * aspectRatio;
+
+    frustum_infinite(-xmax, xmax, -ymax, ymax, near)
+}
+
+/**
+ * Create a perspective projection matrix with reversed depth
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * Points at the near plane are mapped to a depth of `1`, and points at the
+ * far plane are mapped to a depth of `0`. This gives a much more even
+ * distribution of depth precision when used with a floating point depth
+ * buffer and a `[0, 1]` depth range, for example with Direct3D, Vulkan or
+ * `glClipControl(GL_LOWER_LEFT, GL_ZERO_TO_ONE)`.
+ */
+#[inline(always)]
+pub fn perspective_reversed_z<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(radians(fovy / _2));
+    let xmax = ymax * aspectRatio;
+
+    frustum_reversed_z(-xmax, xmax, -ymax, ymax, near, far)
+}
+
+/**
+ * Create a perspective projection matrix with reversed depth and the far
+ * plane at infinity
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * Points at the near plane are mapped to a depth of `1`, and points
+ * infinitely far away approach a depth of `0`. See `perspective_reversed_z`
+ * for the depth range this is intended for.
+ */
+#[inline(always)]
+pub fn perspective_infinite_reversed_z<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T) -> Mat4<T> {
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(radians(fovy / _2));
+    let xmax = ymax * aspectRatio;
+
+    frustum_infinite_reversed_z(-xmax, xmax, -ymax, ymax, near)
+}
+
+/**
+ * Define a view frustum with the far plane at infinity
+ *
+ * This is the limit of `frustum` as `far` approaches infinity.
+ */
+#[inline(always)]
+pub fn frustum_infinite<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    BaseMat4::new((_2 * near) / (right - left), _0, _0, _0,
+                  _0, (_2 * near) / (top - bottom), _0, _0,
+                  (right + left) / (right - left), (top + bottom) / (top - bottom), -_1, -_1,
+                  _0, _0, -_2 * near, _0)
+}
+
+/**
+ * Define a view frustum with reversed depth
+ *
+ * Points at the near plane are mapped to a depth of `1`, and points at the
+ * far plane are mapped to a depth of `0`. See `perspective_reversed_z` for
+ * the depth range this is intended for.
+ */
+#[inline(always)]
+pub fn frustum_reversed_z<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    BaseMat4::new((_2 * near) / (right - left), _0, _0, _0,
+                  _0, (_2 * near) / (top - bottom), _0, _0,
+                  (right + left) / (right - left), (top + bottom) / (top - bottom), near / (far - near), -_1,
+                  _0, _0, (far * near) / (far - near), _0)
+}
+
+/**
+ * Define a view frustum with reversed depth and the far plane at infinity
+ *
+ * This is the limit of `frustum_reversed_z` as `far` approaches infinity.
+ */
+#[inline(always)]
+pub fn frustum_infinite_reversed_z<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    BaseMat4::new((_2 * near) / (right - left), _0, _0, _0,
+                  _0, (_2 * near) / (top - bottom), _0, _0,
+                  (right + left) / (right - left), (top + bottom) / (top - bottom), _0, -_1,
+                  _0, _0, near, _0)
+}
+
+/**
+ * Create a perspective projection matrix for the conventions of `clip`
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * This is equivalent to `perspective` when `clip` is `ClipSpace::opengl()`.
+ */
+#[inline(always)]
+pub fn perspective_for<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T, far: T, clip: &ClipSpace) -> Mat4<T> {
+    convert_projection(&perspective(fovy, aspectRatio, near, far), &ClipSpace::opengl(), clip)
+}
+
+/**
+ * Define a view frustum for the conventions of `clip`
+ *
+ * This is equivalent to `frustum` when `clip` is `ClipSpace::opengl()`. For a
+ * left handed `clip`, `near` and `far` are still given as positive distances
+ * in front of the camera.
+ */
+#[inline(always)]
+pub fn frustum_for<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: &ClipSpace) -> Mat4<T> {
+    convert_projection(&frustum(left, right, bottom, top, near, far), &ClipSpace::opengl(), clip)
+}
+
+/**
+ * Create an orthographic projection matrix for the conventions of `clip`
+ *
+ * This is equivalent to `orthographic` when `clip` is `ClipSpace::opengl()`.
+ */
+#[inline(always)]
+pub fn orthographic_for<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T, clip: &ClipSpace) -> Mat4<T> {
+    convert_projection(&orthographic(left, right, bottom, top, near, far), &ClipSpace::opengl(), clip)
+}
+
+/**
+ * Create a matrix that remaps clip space coordinates from the depth range
+ * and `y` axis direction of `from` to those of `to`
+ *
+ * Premultiplying a projection matrix by this matrix converts its output
+ * between the two conventions. The handedness of eye space is part of the
+ * input of a projection matrix, so it is not affected; use
+ * `convert_projection` to convert that as well.
+ */
+pub fn clip_space_conversion<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(from: &ClipSpace, to: &ClipSpace) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+    let half: T = num::cast(0.5);
+
+    let sy = if from.y == to.y { _1 } else { -_1 };
+
+    let (sz, tz) = match (from.depth, to.depth) {
+        (NegativeOneToOne, ZeroToOne) => (half, half),          // z' = (z + w) / 2
+        (ZeroToOne, NegativeOneToOne) => (_2, -_1),             // z' = 2z - w
+        _                             => (_1, _0),
+    };
+
+    BaseMat4::new(_1, _0, _0, _0,
+                  _0, sy, _0, _0,
+                  _0, _0, sz, _0,
+                  _0, _0, tz, _1)
+}
+
+/**
+ * Convert a projection matrix made for the conventions of `from` to the
+ * conventions of `to`
+ *
+ * The clip space coordinates are remapped with `clip_space_conversion`, and
+ * if the handedness differs the `z` axis of eye space is mirrored, so that
+ * the same points remain in front of the camera.
+ */
+pub fn convert_projection<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(proj: &Mat4<T>, from: &ClipSpace, to: &ClipSpace) -> Mat4<T> {
+    let m = clip_space_conversion(from, to).mul_m(proj);
+
+    if from.handedness == to.handedness {
+        m
+    } else {
+        let mut flip: Mat4<T> = BaseMat::identity();
+        flip.z.z = -flip.z.z;
+        m.mul_m(&flip)
+    }
+}
+
+/**
+ * Create the inverse of a perspective projection matrix
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * This computes the inverse of `perspective(fovy, aspectRatio, near, far)`
+ * directly, which is faster and more accurate than `Mat4::inverse`.
+ */
+#[inline(always)]
+pub fn perspective_inverse<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(radians(fovy / _2));
+    let xmax = ymax * aspectRatio;
+
+    frustum_inverse(-xmax, xmax, -ymax, ymax, near, far)
+}
+
+/**
+ * Create the inverse of a view frustum matrix
+ *
+ * This computes the inverse of `frustum(left, right, bottom, top, near, far)`
+ * directly, which is faster and more accurate than `Mat4::inverse`.
+ */
+#[inline(always)]
+pub fn frustum_inverse<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    let c0r0 = (right - left) / (_2 * near);
+    let c1r1 = (top - bottom) / (_2 * near);
+    let c2r3 = -(far - near) / (_2 * far * near);
+    let c3r0 = (right + left) / (_2 * near);
+    let c3r1 = (top + bottom) / (_2 * near);
+    let c3r3 = (far + near) / (_2 * far * near);
+
+    BaseMat4::new(c0r0,   _0,  _0,   _0,
+                    _0, c1r1,  _0,   _0,
+                    _0,   _0,  _0, c2r3,
+                  c3r0, c3r1, -_1, c3r3)
+}
+
+/**
+ * Create the inverse of an orthographic projection matrix
+ *
+ * This computes the inverse of
+ * `orthographic(left, right, bottom, top, near, far)` directly, which is
+ * faster and more accurate than `Mat4::inverse`.
+ */
+#[inline(always)]
+pub fn orthographic_inverse<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    BaseMat4::new((right - left) / _2, _0, _0, _0,
+                  _0, (top - bottom) / _2, _0, _0,
+                  _0, _0, -(far - near) / _2, _0,
+                  (right + left) / _2, (top + bottom) / _2, -(far + near) / _2, _1)
+}
+
+/**
+ * Map object coordinates to window coordinates
+ *
+ * This is the equivalent of the [gluProject]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluProject.xml) function.
+ *
+ * # Arguments
+ *
+ * * `obj`        - the object coordinates
+ * * `model_view` - the model-view matrix
+ * * `proj`       - the projection matrix
+ * * `viewport`   - the viewport as `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * * `Some(win)` - the window coordinates, with a depth in the range `[0, 1]`
+ * * `None` - if the point projects to infinity, ie: it lies in the plane of
+ *   the camera
+ */
+pub fn project<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(obj: &Vec3<T>, model_view: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    let half: T = num::cast(0.5);
+    let _1: T = num::cast(1);
+
+    let clip = proj.mul_m(model_view).mul_v(&BaseVec4::new(obj.x, obj.y, obj.z, _1));
+    let ndc: Option<Vec3<T>> = FromHomogeneous::from_homogeneous_checked(&clip);
+
+    match ndc {
+        Some(ndc) => Some(BaseVec3::new(viewport.x + viewport.z * (ndc.x + _1) * half,
+                                        viewport.y + viewport.w * (ndc.y + _1) * half,
+                                        (ndc.z + _1) * half)),
+        None => None
+    }
+}
+
+/**
+ * Map window coordinates to object coordinates
+ *
+ * This is the equivalent of the [gluUnProject]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluUnProject.xml) function.
+ *
+ * # Arguments
+ *
+ * * `win`        - the window coordinates, with a depth in the range `[0, 1]`
+ * * `model_view` - the model-view matrix
+ * * `proj`       - the projection matrix
+ * * `viewport`   - the viewport as `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * * `Some(obj)` - the object coordinates
+ * * `None` - if `proj * model_view` is not invertible, or the window
+ *   coordinates correspond to a point at infinity
+ */
+pub fn unproject<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(win: &Vec3<T>, model_view: &Mat4<T>, proj: &Mat4<T>, viewport: &Vec4<T>) -> Option<Vec3<T>> {
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    match proj.mul_m(model_view).inverse() {
+        Some(inv) => {
+            let ndc: Vec4<T> = BaseVec4::new(_2 * (win.x - viewport.x) / viewport.z - _1,
+                                             _2 * (win.y - viewport.y) / viewport.w - _1,
+                                             _2 * win.z - _1,
+                                             _1);
+            FromHomogeneous::from_homogeneous_checked(&inv.mul_v(&ndc))
+        }
+        None => None
+    }
+}
+
+/**
+ * Create the matrix that maps normalized device coordinates to window
+ * coordinates
+ *
+ * This is the transform applied by [glViewport]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/glViewport.xml) and the default
+ * `glDepthRange`, and matches the window coordinates of `project`.
+ *
+ * # Arguments
+ *
+ * * `viewport` - the viewport as `(x, y, width, height)`
+ *
+ * # Return value
+ *
+ * The matrix mapping `[-1, 1]` in each axis to `[x, x + width]`,
+ * `[y, y + height]` and a depth in the range `[0, 1]`
+ */
+#[inline(always)]
+pub fn viewport_transform<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(viewport: &Vec4<T>) -> Mat4<T> {
+    let half: T = num::cast(0.5);
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+
+    BaseMat4::new(viewport.z * half, _0, _0, _0,
+                  _0, viewport.w * half, _0, _0,
+                  _0, _0, half, _0,
+                  viewport.x + viewport.z * half, viewport.y + viewport.w * half, half, _1)
+}
+
+/**
+ * Create the matrix that maps window coordinates to normalized device
+ * coordinates, which is the inverse of `viewport_transform`
+ *
+ * # Arguments
+ *
+ * * `viewport` - the viewport as `(x, y, width, height)`
+ */
+#[inline(always)]
+pub fn viewport_transform_inverse<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(viewport: &Vec4<T>) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    BaseMat4::new(_2 / viewport.z, _0, _0, _0,
+                  _0, _2 / viewport.w, _0, _0,
+                  _0, _0, _2, _0,
+                  -(_2 * viewport.x / viewport.z) - _1, -(_2 * viewport.y / viewport.w) - _1, -_1, _1)
+}
+
+/**
+ * Create a matrix that restricts drawing to a region of the viewport, for
+ * picking objects under the cursor
+ *
+ * This is the equivalent of the [gluPickMatrix]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluPickMatrix.xml) function. The
+ * matrix should be used as `pick * proj`, so the region fills the whole of
+ * clip space.
+ *
+ * # Arguments
+ *
+ * * `centre`   - the centre of the region in window coordinates
+ * * `size`     - the width and height of the region in window coordinates,
+ *                which must both be positive
+ * * `viewport` - the viewport as `(x, y, width, height)`
+ */
+#[inline(always)]
+pub fn pick_matrix<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(centre: &Vec2<T>, size: &Vec2<T>, viewport: &Vec4<T>) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    let sx = viewport.z / size.x;
+    let sy = viewport.w / size.y;
+
+    BaseMat4::new(sx, _0, _0, _0,
+                  _0, sy, _0, _0,
+                  _0, _0, _1, _0,
+                  (viewport.z - _2 * (centre.x - viewport.x)) / size.x,
+                  (viewport.w - _2 * (centre.y - viewport.y)) / size.y,
+                  _0, _1)
+}
+
+/**
+ * Create a two dimensional orthographic projection matrix
+ *
+ * This is the equivalent of the [gluOrtho2D]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/gluOrtho2D.xml) function, which
+ * is `orthographic` with a depth range of `[-1, 1]`.
+ */
+#[inline(always)]
+pub fn orthographic_2d<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(left: T, right: T, bottom: T, top: T) -> Mat4<T> {
+    let _1: T = num::cast(1);
+    orthographic(left, right, bottom, top, -_1, _1)
+}
+
+/**
+ * Create a two dimensional orthographic projection matrix that maps one unit
+ * to one pixel of a viewport
+ *
+ * Integer coordinates lie on the edges between pixels, so the centre of the
+ * pixel at column `i` and row `j` is at `(i + 0.5, j + 0.5)`.
+ *
+ * # Arguments
+ *
+ * * `width`  - the width of the viewport in pixels
+ * * `height` - the height of the viewport in pixels
+ * * `y`      - `YUp` to put the origin at the bottom left corner of the
+ *              viewport, as in OpenGL window coordinates, or `YDown` to put it
+ *              at the top left corner, as is usual for user interfaces
+ */
+#[inline(always)]
+pub fn pixel_orthographic<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(width: T, height: T, y: YAxis) -> Mat4<T> {
+    let _0: T = num::cast(0);
+
+    match y {
+        YUp   => orthographic_2d(_0, width, _0, height),
+        YDown => orthographic_2d(_0, width, height, _0),
+    }
+}
+
+/**
+ * The type of a projection matrix
+ */
+#[deriving(Eq)]
+pub enum ProjectionKind {
+    /// a projection created by `perspective` or `frustum`
+    Perspective,
+    /// a projection created by `orthographic`
+    Orthographic,
+}
+
+/**
+ * The parameters of a projection matrix, as recovered by
+ * `decompose_projection`
+ *
+ * # Fields
+ *
+ * * `kind` - the type of the projection
+ * * `left`, `right`, `bottom`, `top`, `near`, `far` - the arguments that
+ *   recreate the matrix when passed to `frustum` or `orthographic`
+ */
+#[deriving(Eq)]
+pub struct ProjectionParams<T> {
+    kind:   ProjectionKind,
+    left:   T,
+    right:  T,
+    bottom: T,
+    top:    T,
+    near:   T,
+    far:    T,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> ProjectionParams<T> {
+    /**
+     * # Return value
+     *
+     * The vertical field of view in degrees, measured between the bottom and
+     * top planes. This is zero for an orthographic projection.
+     */
+    #[inline(always)]
+    fn fovy(&self) -> T {
+        match self.kind {
+            Perspective  => degrees(atan2(self.top, self.near) - atan2(self.bottom, self.near)),
+            Orthographic => num::cast(0),
+        }
+    }
+
+    /**
+     * # Return value
+     *
+     * The ratio of the width to the height of the viewing volume
+     */
+    #[inline(always)]
+    fn aspect_ratio(&self) -> T {
+        (self.right - self.left) / (self.top - self.bottom)
+    }
+
+    /**
+     * # Return value
+     *
+     * The offset of the centre of the viewing volume from the `z` axis, on the
+     * near plane. This is zero for a projection created by `perspective`.
+     */
+    #[inline(always)]
+    fn offset(&self) -> Vec2<T> {
+        let _2: T = num::cast(2);
+        BaseVec2::new((self.right + self.left) / _2,
+                      (self.top + self.bottom) / _2)
+    }
+
+    /**
+     * # Return value
+     *
+     * The projection matrix described by the parameters
+     */
+    #[inline(always)]
+    fn to_mat4(&self) -> Mat4<T> {
+        match self.kind {
+            Perspective  => frustum(self.left, self.right, self.bottom, self.top, self.near, self.far),
+            Orthographic => orthographic(self.left, self.right, self.bottom, self.top, self.near, self.far),
+        }
+    }
+}
+
+/**
+ * Recover the parameters of a projection matrix created by `perspective`,
+ * `frustum` or `orthographic`
+ *
+ * Matrices made for other clip space conventions are rejected where they can
+ * be told apart from an OpenGL style projection, such as reversed depth
+ * projections and projections with a downwards `y` axis. A perspective
+ * projection with a `[0, 1]` depth range is also a valid OpenGL style
+ * projection with a different near plane, so use `decompose_projection_for`
+ * when the conventions are known.
+ *
+ * # Return value
+ *
+ * * `Ok(params)` - the parameters of the projection
+ * * `Err(msg)` - if the matrix is not an OpenGL style perspective or
+ *   orthographic projection, or if its far plane is at infinity
+ */
+pub fn decompose_projection<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(m: &Mat4<T>) -> Result<ProjectionParams<T>, ~str> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+
+    // the elements that are zero in both kinds of projection
+    if !(m.x.y.fuzzy_eq(&_0) && m.x.z.fuzzy_eq(&_0) && m.x.w.fuzzy_eq(&_0) &&
+         m.y.x.fuzzy_eq(&_0) && m.y.z.fuzzy_eq(&_0) && m.y.w.fuzzy_eq(&_0)) {
+        return Err(~"the matrix is not a projection: it has rotation or shear");
+    }
+
+    if m.x.x.fuzzy_eq(&_0) || m.y.y.fuzzy_eq(&_0) || m.z.z.fuzzy_eq(&_0) {
+        return Err(~"the matrix is not a projection: it is singular");
+    }
+
+    let params = if m.z.w.fuzzy_eq(&-_1) && m.w.w.fuzzy_eq(&_0) &&
+                    m.w.x.fuzzy_eq(&_0) && m.w.y.fuzzy_eq(&_0) {
+        if m.z.z.fuzzy_eq(&-_1) || m.z.z.fuzzy_eq(&_1) {
+            return Err(~"the perspective projection has a plane at infinity");
+        }
+
+        let near = m.w.z / (m.z.z - _1);
+        let far  = m.w.z / (m.z.z + _1);
+
+        ProjectionParams { kind:   Perspective,
+                           left:   near * (m.z.x - _1) / m.x.x,
+                           right:  near * (m.z.x + _1) / m.x.x,
+                           bottom: near * (m.z.y - _1) / m.y.y,
+                           top:    near * (m.z.y + _1) / m.y.y,
+                           near:   near,
+                           far:    far }
+    } else if m.z.w.fuzzy_eq(&_0) && m.w.w.fuzzy_eq(&_1) &&
+              m.z.x.fuzzy_eq(&_0) && m.z.y.fuzzy_eq(&_0) {
+        ProjectionParams { kind:   Orthographic,
+                           left:   (-_1 - m.w.x) / m.x.x,
+                           right:  ( _1 - m.w.x) / m.x.x,
+                           bottom: (-_1 - m.w.y) / m.y.y,
+                           top:    ( _1 - m.w.y) / m.y.y,
+                           near:   (m.w.z + _1) / m.z.z,
+                           far:    (m.w.z - _1) / m.z.z }
+    } else {
+        return Err(~"the matrix is neither a perspective nor an orthographic projection");
+    };
+
+    if !(params.left < params.right) || !(params.bottom < params.top) {
+        return Err(~"the projection is mirrored: it may be for a clip space with a downwards y axis");
+    }
+
+    if !(params.near < params.far) || (params.kind == Perspective && !(params.near > _0)) {
+        return Err(~"the depth planes are out of order: the projection may use reversed depth or a different depth range");
+    }
+
+    Ok(params)
+}
+
+/**
+ * Recover the parameters of a projection matrix created by `perspective_for`,
+ * `frustum_for` or `orthographic_for` with the conventions of `clip`
+ *
+ * The returned parameters recreate the matrix when passed to `frustum_for`
+ * or `orthographic_for` with the same `clip`. Their `to_mat4` method returns
+ * the equivalent OpenGL style projection.
+ */
+#[inline(always)]
+pub fn decompose_projection_for<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(m: &Mat4<T>, clip: &ClipSpace) -> Result<ProjectionParams<T>, ~str> {
+    decompose_projection(&convert_projection(m, clip, &ClipSpace::opengl()))
+}
+
+/**
+ * A projection for an eye that is not on the axis of the viewing volume, as
+ * created by `generalized_perspective` and `stereo_pair`
+ *
+ * # Fields
+ *
+ * * `proj` - the projection matrix
+ * * `view` - the view adjustment matrix, which transforms points into the eye
+ *            space of the projection. This should be applied after the
+ *            existing view matrix, ie: `proj * view * existing_view`.
+ */
+#[deriving(Eq)]
+pub struct EyeProjection<T> {
+    proj: Mat4<T>,
+    view: Mat4<T>,
+}
+
+/**
+ * Create a projection for an eye looking through a rectangular screen, which
+ * can be positioned arbitrarily relative to the eye. This is used for head
+ * tracked displays, such as CAVEs.
+ *
+ * The eye and the corners of the screen must all be in the same coordinate
+ * system, for example that of the tracking system.
+ *
+ * - [Generalized Perspective Projection]
+ *   (http://csc.lsu.edu/~kooima/articles/genperspective/)
+ *
+ * # Arguments
+ *
+ * * `eye`         - the position of the eye
+ * * `lower_left`  - the lower left corner of the screen
+ * * `lower_right` - the lower right corner of the screen
+ * * `upper_left`  - the upper left corner of the screen
+ * * `near`        - the distance of the near plane from the eye
+ * * `far`         - the distance of the far plane from the eye
+ */
+pub fn generalized_perspective<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(eye: &Vec3<T>, lower_left: &Vec3<T>, lower_right: &Vec3<T>, upper_left: &Vec3<T>, near: T, far: T) -> EyeProjection<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+
+    // the orthonormal basis of the screen
+    let vr = lower_right.sub_v(lower_left).normalize();
+    let vu = upper_left.sub_v(lower_left).normalize();
+    let vn = vr.cross(&vu).normalize();
+
+    // the vectors from the eye to the corners of the screen
+    let va = lower_left.sub_v(eye);
+    let vb = lower_right.sub_v(eye);
+    let vc = upper_left.sub_v(eye);
+
+    // the distance from the eye to the plane of the screen
+    let d = -vn.dot(&va);
+    let scale = near / d;
+
+    let proj = frustum(vr.dot(&va) * scale, vr.dot(&vb) * scale,
+                       vu.dot(&va) * scale, vu.dot(&vc) * scale,
+                       near, far);
+
+    // rotate the screen into the xy plane, with the eye at the origin
+    let view = BaseMat4::new(vr.x, vu.x, vn.x, _0,
+                             vr.y, vu.y, vn.y, _0,
+                             vr.z, vu.z, vn.z, _0,
+                             -vr.dot(eye), -vu.dot(eye), -vn.dot(eye), _1);
+
+    EyeProjection { proj: proj, view: view }
+}
+
+/**
+ * Create the projections for a pair of stereo cameras with parallel axes,
+ * using asymmetric frusta so that there is no parallax on the plane at the
+ * convergence distance
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * # Arguments
+ *
+ * * `fovy`        - the vertical field of view of the combined view
+ * * `aspectRatio` - the ratio of the width to the height of the viewport
+ * * `near`        - the distance of the near plane
+ * * `far`         - the distance of the far plane
+ * * `ipd`         - the interpupillary distance, ie: the distance between the
+ *                   eyes
+ * * `convergence` - the distance of the plane with no parallax
+ *
+ * # Return value
+ *
+ * The projections for the left and right eyes
+ */
+pub fn stereo_pair<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(fovy: T, aspectRatio: T, near: T, far: T, ipd: T, convergence: T) -> (EyeProjection<T>, EyeProjection<T>) {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    let ymax = near * tan(radians(fovy / _2));
+    let xmax = ymax * aspectRatio;
+
+    let half_ipd = ipd / _2;
+    let shift = half_ipd * near / convergence;
+
+    let left = EyeProjection {
+        proj: frustum(-xmax + shift, xmax + shift, -ymax, ymax, near, far),
+        view: BaseMat4::new(_1, _0, _0, _0,
+                            _0, _1, _0, _0,
+                            _0, _0, _1, _0,
+                            half_ipd, _0, _0, _1),
+    };
+    let right = EyeProjection {
+        proj: frustum(-xmax - shift, xmax - shift, -ymax, ymax, near, far),
+        view: BaseMat4::new(_1, _0, _0, _0,
+                            _0, _1, _0, _0,
+                            _0, _0, _1, _0,
+                            -half_ipd, _0, _0, _1),
+    };
+
+    (left, right)
+}
+
+/**
+ * Modify a perspective projection matrix so that its near plane is replaced
+ * by an arbitrary clipping plane, such as the plane of a mirror or a portal
+ *
+ * The far plane can not be preserved exactly, but it is moved as little as
+ * possible: it still passes through the far corner of the original frustum
+ * that is furthest from the clipping plane. Depth precision is reduced as the
+ * angle between the clipping plane and the original near plane increases.
+ *
+ * - [Oblique View Frustum Depth Projection and Clipping]
+ *   (http://www.terathon.com/lengyel/Lengyel-Oblique.pdf)
+ *
+ * # Arguments
+ *
+ * * `proj`       - a projection matrix created by `perspective` or `frustum`
+ * * `clip_plane` - the plane in eye space, as `(a, b, c, d)` such that the
+ *                  points that are kept satisfy `a*x + b*y + c*z + d >= 0`.
+ *                  The camera must be on the other side of the plane, ie:
+ *                  `d` must be negative.
+ */
+pub fn oblique_near_plane<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(proj: &Mat4<T>, clip_plane: &Vec4<T>) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+    let _2: T = num::cast(2);
+
+    let sgn = |x: T| -> T {
+        if x > _0 { _1 } else if x < _0 { -_1 } else { _0 }
+    };
+
+    // the corner of the frustum opposite the clipping plane, in eye space
+    let q: Vec4<T> = BaseVec4::new((sgn(clip_plane.x) + proj.z.x) / proj.x.x,
+                                   (sgn(clip_plane.y) + proj.z.y) / proj.y.y,
+                                   -_1,
+                                   (_1 + proj.z.z) / proj.w.z);
+
+    // replace the third row, keeping the fourth
+    let r3 = proj.row(3);
+    let c = clip_plane.mul_t(_2 * r3.dot(&q) / clip_plane.dot(&q)).sub_v(&r3);
+
+    let mut m = *proj;
+    m.x.z = c.x;
+    m.y.z = c.y;
+    m.z.z = c.z;
+    m.w.z = c.w;
+    m
+}
+
+/**
+ * Offset a projection matrix in normalized device coordinates, for example to
+ * jitter the projection by a fraction of a pixel
+ *
+ * This works for any projection matrix, including those created by
+ * `perspective`, `frustum` and `orthographic`. Every point is moved by the
+ * same offset after the perspective divide.
+ *
+ * # Arguments
+ *
+ * * `proj`   - the projection matrix
+ * * `offset` - the offset in normalized device coordinates. An offset of one
+ *              pixel in a viewport of size `(width, height)` is
+ *              `(2 / width, 2 / height)`.
+ */
+#[inline(always)]
+pub fn jitter<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(proj: &Mat4<T>, offset: &Vec2<T>) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+
+    // translate the clip space coordinates by `offset * w`
+    let translation: Mat4<T> = BaseMat4::new(      _1,       _0, _0, _0,
+                                                   _0,       _1, _0, _0,
+                                                   _0,       _0, _1, _0,
+                                             offset.x, offset.y, _0, _1);
+    translation.mul_m(proj)
+}
+
+/**
+ * # Return value
+ *
+ * The element at `index` of the Halton sequence with the given base, which is
+ * in the range `[0, 1)`. The element at index zero is always zero.
+ *
+ * - [Halton sequence](http://en.wikipedia.org/wiki/Halton_sequence)
+ */
+pub fn halton<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(index: uint, base: uint) -> T {
+    let _1: T = num::cast(1);
+    let b: T = num::cast(base);
+
+    let mut result: T = num::cast(0);
+    let mut f = _1 / b;
+    let mut i = index;
+
+    while i > 0 {
+        result = result + f * num::cast(i % base);
+        i = i / base;
+        f = f / b;
+    }
+
+    result
+}
+
+/**
+ * # Return value
+ *
+ * The element at `index` of the two dimensional Halton sequence with bases 2
+ * and 3, which is in the range `[0, 1)` in each dimension
+ */
+#[inline(always)]
+pub fn halton_2_3<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(index: uint) -> Vec2<T> {
+    BaseVec2::new(halton(index, 2), halton(index, 3))
+}
+
+/**
+ * Create the projection matrices for a frame rendered with temporal
+ * antialiasing
+ *
+ * The jitter is a sub-pixel offset of up to half a pixel in each direction,
+ * taken from element `frame + 1` of the Halton(2, 3) sequence. The sequence
+ * can be shortened by wrapping `frame`, for example to 8 or 16 frames.
+ *
+ * # Arguments
+ *
+ * * `proj`   - the projection matrix
+ * * `frame`  - the index of the frame
+ * * `width`  - the width of the viewport in pixels
+ * * `height` - the height of the viewport in pixels
+ *
+ * # Return value
+ *
+ * The unjittered and jittered projection matrices
+ */
+pub fn jittered_pair<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(proj: &Mat4<T>, frame: uint, width: T, height: T) -> (Mat4<T>, Mat4<T>) {
+    let half: T = num::cast(0.5);
+    let _2: T = num::cast(2);
+
+    let sample: Vec2<T> = halton_2_3(frame + 1);
+    let offset: Vec2<T> = BaseVec2::new(_2 * (sample.x - half) / width,
+                                        _2 * (sample.y - half) / height);
+
+    (*proj, jitter(proj, &offset))
+}
+
+/**
+ * How the sensor of a `PhysicalCamera` is fitted to a viewport with a
+ * different aspect ratio
+ */
+#[deriving(Eq)]
+pub enum GateFit {
+    /// the width of the sensor fills the width of the viewport
+    FitHorizontal,
+    /// the height of the sensor fills the height of the viewport
+    FitVertical,
+    /// the whole sensor is visible, filling either the width or the height of
+    /// the viewport
+    FitInside,
+}
+
+/**
+ * A camera described by the physical properties of its lens and sensor
+ *
+ * # Fields
+ *
+ * * `focal_length`  - the focal length of the lens, in millimetres
+ * * `sensor_width`  - the width of the sensor, in millimetres
+ * * `sensor_height` - the height of the sensor, in millimetres
+ * * `shift`         - the lens shift, as a fraction of the width and height
+ *                     of the fitted sensor. A shift of `(0.5, 0)` moves the
+ *                     image by half of its width.
+ * * `gate_fit`      - how the sensor is fitted to the viewport
+ */
+#[deriving(Eq)]
+pub struct PhysicalCamera<T> {
+    focal_length:  T,
+    sensor_width:  T,
+    sensor_height: T,
+    shift:         Vec2<T>,
+    gate_fit:      GateFit,
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> PhysicalCamera<T> {
+    /**
+     * Construct a camera with no lens shift, which fits the whole sensor
+     * inside the viewport
+     *
+     * # Arguments
+     *
+     * * `focal_length`  - the focal length of the lens, in millimetres
+     * * `sensor_width`  - the width of the sensor, in millimetres
+     * * `sensor_height` - the height of the sensor, in millimetres
+     */
+    #[inline(always)]
+    fn new(focal_length: T, sensor_width: T, sensor_height: T) -> PhysicalCamera<T> {
+        PhysicalCamera { focal_length:  focal_length,
+                         sensor_width:  sensor_width,
+                         sensor_height: sensor_height,
+                         shift:         BaseVec2::new(num::cast(0), num::cast(0)),
+                         gate_fit:      FitInside }
+    }
+
+    /**
+     * # Return value
+     *
+     * A copy of the camera with the lens shift `(x, y)`
+     */
+    #[inline(always)]
+    fn with_shift(&self, x: T, y: T) -> PhysicalCamera<T> {
+        PhysicalCamera { shift: BaseVec2::new(x, y), .. *self }
+    }
+
+    /**
+     * # Return value
+     *
+     * A copy of the camera which fits the sensor to the viewport using
+     * `gate_fit`
+     */
+    #[inline(always)]
+    fn with_gate_fit(&self, gate_fit: GateFit) -> PhysicalCamera<T> {
+        PhysicalCamera { gate_fit: gate_fit, .. *self }
+    }
+
+    /**
+     * # Return value
+     *
+     * The ratio of the width to the height of the sensor
+     */
+    #[inline(always)]
+    fn aspect_ratio(&self) -> T {
+        self.sensor_width / self.sensor_height
+    }
+
+    /**
+     * # Return value
+     *
+     * The width and height, in millimetres, of the area of the sensor plane
+     * that is visible in a viewport with the given aspect ratio
+     */
+    fn gate(&self, aspectRatio: T) -> Vec2<T> {
+        let horizontal = match self.gate_fit {
+            FitHorizontal => true,
+            FitVertical   => false,
+            FitInside     => self.aspect_ratio() >= aspectRatio,
+        };
+
+        if horizontal {
+            BaseVec2::new(self.sensor_width, self.sensor_width / aspectRatio)
+        } else {
+            BaseVec2::new(self.sensor_height * aspectRatio, self.sensor_height)
+        }
+    }
+
+    /**
+     * # Return value
+     *
+     * The vertical field of view in degrees, as expected by `perspective`,
+     * for a viewport with the given aspect ratio. The lens shift is ignored.
+     */
+    #[inline(always)]
+    fn fovy(&self, aspectRatio: T) -> T {
+        let _2: T = num::cast(2);
+        _2 * degrees(atan2(self.gate(aspectRatio).y, _2 * self.focal_length))
+    }
+
+    /**
+     * Create the projection matrix for a viewport with the given aspect ratio
+     *
+     * Without any lens shift, this is the same as
+     * `perspective(self.fovy(aspectRatio), aspectRatio, near, far)`.
+     */
+    fn to_mat4(&self, aspectRatio: T, near: T, far: T) -> Mat4<T> {
+        let half: T = num::cast(0.5);
+
+        let gate = self.gate(aspectRatio);
+
+        // the extent of the image on the near plane
+        let width  = near * gate.x / self.focal_length;
+        let height = near * gate.y / self.focal_length;
+
+        let x = width * self.shift.x;
+        let y = height * self.shift.y;
+
+        frustum(x - width * half, x + width * half,
+                y - height * half, y + height * half,
+                near, far)
+    }
+}
@@ -13,8 +13,9 @@ use core::num::One::one;
 use std::cmp::{FuzzyEq, FUZZY_EPSILON};
 use numeric::*;
 
-use mat::{Mat3, BaseMat3};
-use vec::{Vec3, BaseVec3, AffineVec, NumVec, NumVec3};
+use angle::{Angle, Rad, Deg};
+use mat::{BaseMat, Mat3, BaseMat3, Mat4, BaseMat4};
+use vec::{Vec3, BaseVec3, Vec4, BaseVec4, AffineVec, NumVec, NumVec3};
 use vec::{vec3, dvec3, Vec3f, Vec3f32, Vec3f64};
 
 /**
@@ -84,20 +85,20 @@ pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,
 
     #[inline(always)]
     fn from_angle_x(radians: T) -> Quat<T> {
-        let _2 = num::cast(2);
-        Quat::new(cos(radians / _2), sin(radians), zero(), zero())
+        let half = radians / num::cast(2);
+        Quat::new(cos(half), sin(half), zero(), zero())
     }
 
     #[inline(always)]
     fn from_angle_y(radians: T) -> Quat<T> {
-        let _2 = num::cast(2);
-        Quat::new(cos(radians / _2), zero(), sin(radians), zero())
+        let half = radians / num::cast(2);
+        Quat::new(cos(half), zero(), sin(half), zero())
     }
 
     #[inline(always)]
     fn from_angle_z(radians: T) -> Quat<T> {
-        let _2 = num::cast(2);
-        Quat::new(cos(radians / _2), zero(), zero(), sin(radians))
+        let half = radians / num::cast(2);
+        Quat::new(cos(half), zero(), zero(), sin(half))
     }
 
     #[inline(always)]
@@ -119,6 +120,96 @@ pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,
         Quat::from_sv(cos(half), axis.mul_t(sin(half)))
     }
 
+    /**
+     * Construct a quaternion from an angular rotation around the `x` axis,
+     * with the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_x(angle: Rad<T>) -> Quat<T> {
+        Quat::from_angle_x(*angle)
+    }
+
+    /**
+     * Construct a quaternion from an angular rotation around the `y` axis,
+     * with the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_y(angle: Rad<T>) -> Quat<T> {
+        Quat::from_angle_y(*angle)
+    }
+
+    /**
+     * Construct a quaternion from an angular rotation around the `z` axis,
+     * with the angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad_z(angle: Rad<T>) -> Quat<T> {
+        Quat::from_angle_z(*angle)
+    }
+
+    /**
+     * Construct a quaternion from Euler angles typed as radians, as for
+     * `from_angle_xyz`
+     */
+    #[inline(always)]
+    fn from_rad_xyz(x: Rad<T>, y: Rad<T>, z: Rad<T>) -> Quat<T> {
+        Quat::from_angle_xyz(*x, *y, *z)
+    }
+
+    /**
+     * Construct a quaternion from an axis and an angular rotation typed as
+     * radians
+     */
+    #[inline(always)]
+    fn from_rad_axis(angle: Rad<T>, axis: &Vec3<T>) -> Quat<T> {
+        Quat::from_angle_axis(*angle, axis)
+    }
+
+    /**
+     * Construct a quaternion from an angular rotation around the `x` axis,
+     * with the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_x(angle: Deg<T>) -> Quat<T> {
+        Quat::from_rad_x(angle.to_rad())
+    }
+
+    /**
+     * Construct a quaternion from an angular rotation around the `y` axis,
+     * with the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_y(angle: Deg<T>) -> Quat<T> {
+        Quat::from_rad_y(angle.to_rad())
+    }
+
+    /**
+     * Construct a quaternion from an angular rotation around the `z` axis,
+     * with the angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg_z(angle: Deg<T>) -> Quat<T> {
+        Quat::from_rad_z(angle.to_rad())
+    }
+
+    /**
+     * Construct a quaternion from Euler angles typed as degrees, as for
+     * `from_angle_xyz`
+     */
+    #[inline(always)]
+    fn from_deg_xyz(x: Deg<T>, y: Deg<T>, z: Deg<T>) -> Quat<T> {
+        Quat::from_rad_xyz(x.to_rad(), y.to_rad(), z.to_rad())
+    }
+
+    /**
+     * Construct a quaternion from an axis and an angular rotation typed as
+     * degrees
+     */
+    #[inline(always)]
+    fn from_deg_axis(angle: Deg<T>, axis: &Vec3<T>) -> Quat<T> {
+        Quat::from_rad_axis(angle.to_rad(), axis)
+    }
+
     #[inline(always)]
     fn from_axes(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Quat<T> {
         let m: Mat3<T> = BaseMat3::from_axes(x, y, z); m.to_quat()
@@ -277,6 +368,68 @@ pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,
         self.mul_t(one::<T>()/self.magnitude())
     }
 
+    /**
+     * # Return value
+     *
+     * The quaternion in the hemisphere where `s >= 0`. Since `q` and `-q`
+     * represent the same rotation, this gives each rotation a single
+     * representation, for example for use as a cache key. When `s` is zero the
+     * first non-zero imaginary component is made positive.
+     */
+    #[inline(always)]
+    fn canonicalize(&self) -> Quat<T> {
+        if self.s > zero() {
+            *self
+        } else if self.s < zero() {
+            -*self
+        } else if self.v.x != zero() {
+            if self.v.x > zero() { *self } else { -*self }
+        } else if self.v.y != zero() {
+            if self.v.y > zero() { *self } else { -*self }
+        } else {
+            if self.v.z >= zero() { *self } else { -*self }
+        }
+    }
+
+    /**
+     * # Return value
+     *
+     * The angle of the shortest rotation from the quaternion to `other` in
+     * radians, in the range `[0, π]`. Both quaternions should be normalized
+     * first.
+     */
+    #[inline(always)]
+    fn angle_to(&self, other: &Quat<T>) -> T {
+        let _2: T = num::cast(2);
+        // `atan2` keeps its precision for small angles, where `acos` of the
+        // dot product does not
+        let d = self.conjugate().mul_q(other);
+        _2 * atan2(d.v.length(), abs(d.s))    // `q` and `-q` are the same rotation
+    }
+
+    /**
+     * # Return value
+     *
+     * `true` if the quaternion and `other` represent approximately the same
+     * rotation, that is if it is approximately equal to either `other` or
+     * `-other`
+     */
+    #[inline(always)]
+    fn rotation_fuzzy_eq(&self, other: &Quat<T>) -> bool {
+        self.rotation_fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    /**
+     * # Return value
+     *
+     * `true` if the quaternion and `other` represent the same rotation to
+     * within `epsilon`
+     */
+    #[inline(always)]
+    fn rotation_fuzzy_eq_eps(&self, other: &Quat<T>, epsilon: &T) -> bool {
+        self.fuzzy_eq_eps(other, epsilon) || self.fuzzy_eq_eps(&-*other, epsilon)
+    }
+
     /**
      * Normalised linear interpolation
      *
@@ -331,6 +484,201 @@ pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,
         }
     }
 
+    /**
+     * Spherical linear interpolation along the shortest arc
+     *
+     * `q` and `-q` describe the same rotation, so `other` is negated first if
+     * it lies in the opposite hemisphere to the quaternion. Unlike `slerp`,
+     * this never turns through more than 180 degrees, whatever the signs of
+     * the quaternions.
+     */
+    #[inline(always)]
+    fn slerp_shortest(&self, other: &Quat<T>, amount: T) -> Quat<T> {
+        if self.dot(other) < zero() {
+            self.slerp(&-*other, amount)
+        } else {
+            self.slerp(other, amount)
+        }
+    }
+
+    /**
+     * Weighted average of a set of rotations
+     *
+     * This uses Markley's method: the average is the eigenvector belonging to
+     * the largest eigenvalue of the 4 x 4 matrix `M = Σ wᵢ qᵢ qᵢᵀ`, which is
+     * found by power iteration. Unlike chaining `nlerp` the result does not
+     * depend on the order of the rotations, and `q` and `-q` contribute the
+     * same rotation.
+     *
+     * # Arguments
+     *
+     * * `quats`   - the normalized quaternions to average. There must be at
+     *               least one.
+     * * `weights` - the weight of each quaternion, with the same length as
+     *               `quats`. The total weight must be positive.
+     *
+     * # Performance notes
+     *
+     * If the rotations are known to lie close together, `average_nlerp` gives
+     * a good approximation for a fraction of the cost.
+     *
+     * - [Averaging Quaternions]
+     *   (http://www.acsu.buffalo.edu/~johnc/ave_quat07.pdf)
+     */
+    fn average(quats: &[Quat<T>], weights: &[T]) -> Quat<T> {
+        assert!(quats.len() > 0 && quats.len() == weights.len());
+
+        let mut m: Mat4<T> = BaseMat::zero();
+
+        for uint::range(0, quats.len()) |i| {
+            let q = quats[i];
+            let v: Vec4<T> = BaseVec4::new(q.s, q.v.x, q.v.y, q.v.z);
+            let w = weights[i];
+
+            m.add_self_m(&BaseMat4::from_cols(v.mul_t(v.x * w),
+                                              v.mul_t(v.y * w),
+                                              v.mul_t(v.z * w),
+                                              v.mul_t(v.w * w)));
+        }
+
+        // the accumulated `nlerp` is already close to the dominant eigenvector,
+        // so the power iteration only needs a few steps to converge. This also
+        // checks that the total weight is positive.
+        let start = Quat::average_nlerp(quats, weights);
+        let mut v: Vec4<T> = BaseVec4::new(start.s, start.v.x, start.v.y, start.v.z);
+
+        for uint::range(0, 32) |_| {
+            let next = m.mul_v(&v).normalize();
+            let converged = next.fuzzy_eq(&v);
+            v = next;
+            if converged { break; }
+        }
+
+        Quat::new(v.x, v.y, v.z, v.w)
+    }
+
+    /**
+     * Weighted average of a set of rotations, computed by accumulating the
+     * quaternions in the same hemisphere and normalizing the sum
+     *
+     * # Arguments
+     *
+     * * `quats`   - the normalized quaternions to average. There must be at
+     *               least one.
+     * * `weights` - the weight of each quaternion, with the same length as
+     *               `quats`. The total weight must be positive.
+     *
+     * # Return value
+     *
+     * An approximation of `average` that is only accurate when the rotations
+     * lie close together
+     */
+    fn average_nlerp(quats: &[Quat<T>], weights: &[T]) -> Quat<T> {
+        assert!(quats.len() > 0 && quats.len() == weights.len());
+
+        let mut sum = Quat::zero();
+        let mut total: T = zero();
+
+        for uint::range(0, quats.len()) |i| {
+            let q = if quats[i].dot(&quats[0]) < zero() { -quats[i] } else { quats[i] };
+            sum = sum.add_q(&q.mul_t(weights[i]));
+            total = total + weights[i];
+        }
+
+        // with no weight there is nothing to normalize
+        assert!(total > zero());
+
+        sum.normalize()
+    }
+
+    /**
+     * The time derivative of the quaternion, ie: `q' = ½ ω q`
+     *
+     * # Arguments
+     *
+     * * `omega` - the angular velocity in radians per unit of time, expressed
+     *             in world space
+     */
+    #[inline(always)]
+    fn derivative(&self, omega: &Vec3<T>) -> Quat<T> {
+        let half: T = num::cast(0.5);
+        Quat::from_sv(zero(), omega.mul_t(half)).mul_q(self)
+    }
+
+    /**
+     * Integrate the orientation over a time step using the exponential map
+     *
+     * # Arguments
+     *
+     * * `omega` - the angular velocity in radians per unit of time, expressed
+     *             in world space
+     * * `dt`    - the length of the time step
+     *
+     * # Return value
+     *
+     * The normalized orientation after rotating by `omega` for `dt`. This is
+     * exact for a constant angular velocity.
+     */
+    #[inline(always)]
+    fn integrate(&self, omega: &Vec3<T>, dt: T) -> Quat<T> {
+        let theta = omega.length() * dt;
+
+        if theta.fuzzy_eq(&zero()) {
+            self.integrate_linear(omega, dt)                    // the axis is undefined for tiny rotations
+        } else {
+            Quat::from_angle_axis(theta, &omega.normalize()).mul_q(self).normalize()
+        }
+    }
+
+    /**
+     * Integrate the orientation over a time step using a first-order (Euler)
+     * approximation, ie: `q + q' dt`
+     *
+     * # Return value
+     *
+     * The normalized orientation after rotating by `omega` for `dt`
+     *
+     * # Performance notes
+     *
+     * This avoids the trigonometric functions used in `integrate`, but is only
+     * accurate when `omega * dt` is small.
+     */
+    #[inline(always)]
+    fn integrate_linear(&self, omega: &Vec3<T>, dt: T) -> Quat<T> {
+        self.add_q(&self.derivative(omega).mul_t(dt)).normalize()
+    }
+
+    /**
+     * Recover the angular velocity that rotates the quaternion to `other`
+     * over a time step. This is the inverse of `integrate`.
+     *
+     * # Arguments
+     *
+     * * `other` - the orientation at the end of the time step
+     * * `dt`    - the length of the time step
+     *
+     * # Return value
+     *
+     * The angular velocity in radians per unit of time, expressed in world
+     * space. The shortest rotation between the two orientations is used.
+     */
+    #[inline(always)]
+    fn angular_velocity(&self, other: &Quat<T>, dt: T) -> Vec3<T> {
+        let _2: T = num::cast(2);
+
+        let mut delta = other.mul_q(&self.conjugate());
+        if delta.s < zero() { delta = -delta; }                 // take the shortest path
+
+        let sin_half = delta.v.length();
+
+        if sin_half.fuzzy_eq(&zero()) {
+            delta.v.mul_t(_2 / dt)                              // small angle approximation
+        } else {
+            let theta = _2 * atan2(sin_half, delta.s);
+            delta.v.mul_t(theta / (sin_half * dt))
+        }
+    }
+
     /**
      * # Return value
      *
@@ -368,6 +716,14 @@ pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,
                            xy2 - sz2, _1 - xx2 - zz2,      yz2 + sx2,
                            xz2 + sy2,      yz2 - sx2, _1 - xx2 - yy2)
     }
+
+    /**
+     * Convert the quaternion to a 4 x 4 rotation matrix
+     */
+    #[inline(always)]
+    fn to_mat4(&self) -> Mat4<T> {
+        self.to_mat3().to_mat4()
+    }
 }
 
 impl<T:Copy> Index<uint, T> for Quat<T> {
@@ -413,8 +769,20 @@ macro_rules! quat_type(
             #[inline(always)] fn from_angle_xyz(radians_x: $T, radians_y: $T, radians_z: $T)
                 -> $name { Quat::from_angle_xyz(radians_x, radians_y, radians_z) }
             #[inline(always)] fn from_angle_axis(radians: $T, axis: &$V) -> $name { Quat::from_angle_axis(radians, axis) }
+            #[inline(always)] fn from_rad_x(angle: Rad<$T>) -> $name { Quat::from_rad_x(angle) }
+            #[inline(always)] fn from_rad_y(angle: Rad<$T>) -> $name { Quat::from_rad_y(angle) }
+            #[inline(always)] fn from_rad_z(angle: Rad<$T>) -> $name { Quat::from_rad_z(angle) }
+            #[inline(always)] fn from_rad_xyz(x: Rad<$T>, y: Rad<$T>, z: Rad<$T>) -> $name { Quat::from_rad_xyz(x, y, z) }
+            #[inline(always)] fn from_rad_axis(angle: Rad<$T>, axis: &$V) -> $name { Quat::from_rad_axis(angle, axis) }
+            #[inline(always)] fn from_deg_x(angle: Deg<$T>) -> $name { Quat::from_deg_x(angle) }
+            #[inline(always)] fn from_deg_y(angle: Deg<$T>) -> $name { Quat::from_deg_y(angle) }
+            #[inline(always)] fn from_deg_z(angle: Deg<$T>) -> $name { Quat::from_deg_z(angle) }
+            #[inline(always)] fn from_deg_xyz(x: Deg<$T>, y: Deg<$T>, z: Deg<$T>) -> $name { Quat::from_deg_xyz(x, y, z) }
+            #[inline(always)] fn from_deg_axis(angle: Deg<$T>, axis: &$V) -> $name { Quat::from_deg_axis(angle, axis) }
             #[inline(always)] fn from_axes(x: $V, y: $V, z: $V) -> $name { Quat::from_axes(x, y, z) }
             #[inline(always)] fn look_at(dir: &$V, up: &$V) -> $name { Quat::look_at(dir, up) }
+            #[inline(always)] fn average(quats: &[$name], weights: &[$T]) -> $name { Quat::average(quats, weights) }
+            #[inline(always)] fn average_nlerp(quats: &[$name], weights: &[$T]) -> $name { Quat::average_nlerp(quats, weights) }
 
             #[inline(always)] fn dim() -> uint { 4 }
             #[inline(always)] fn size_of() -> uint { sys::size_of::<$name>() }
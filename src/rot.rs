@@ -0,0 +1,496 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+
+use angle::{Angle, Rad, Deg};
+use mat::{BaseMat, Mat2, BaseMat2, Mat3, BaseMat3};
+use quat::Quat;
+use vec::{Vec2, BaseVec2, Vec3, AffineVec, NumVec2};
+
+/**
+ * A 2-dimensional rotation
+ *
+ * This allows animation and physics code to be written once for any of the
+ * 2-dimensional rotation types.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ */
+pub trait Rotation2<T> {
+    /**
+     * # Return value
+     *
+     * The rotation by zero radians
+     */
+    fn identity() -> Self;
+
+    /**
+     * # Return value
+     *
+     * The vector rotated by the rotation
+     */
+    fn rotate_vector(&self, vec: &Vec2<T>) -> Vec2<T>;
+
+    /**
+     * # Return value
+     *
+     * The rotation that applies `other` followed by the rotation
+     */
+    fn concat(&self, other: &Self) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The rotation in the opposite direction
+     */
+    fn invert(&self) -> Self;
+
+    /**
+     * Convert the rotation to a 2 x 2 rotation matrix
+     */
+    fn to_mat2(&self) -> Mat2<T>;
+
+    /**
+     * Spherical linear interpolation
+     *
+     * # Return value
+     *
+     * The rotation at `amount` along the shortest arc from the rotation to
+     * `other`
+     */
+    fn slerp(&self, other: &Self, amount: T) -> Self;
+}
+
+/**
+ * A 3-dimensional rotation
+ *
+ * This allows animation and physics code to be written once for any of the
+ * 3-dimensional rotation types.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ */
+pub trait Rotation3<T> {
+    /**
+     * # Return value
+     *
+     * The rotation by zero radians
+     */
+    fn identity() -> Self;
+
+    /**
+     * # Return value
+     *
+     * The vector rotated by the rotation
+     */
+    fn rotate_vector(&self, vec: &Vec3<T>) -> Vec3<T>;
+
+    /**
+     * # Return value
+     *
+     * The rotation that applies `other` followed by the rotation
+     */
+    fn concat(&self, other: &Self) -> Self;
+
+    /**
+     * # Return value
+     *
+     * The rotation in the opposite direction
+     */
+    fn invert(&self) -> Self;
+
+    /**
+     * Convert the rotation to a 3 x 3 rotation matrix
+     */
+    fn to_mat3(&self) -> Mat3<T>;
+
+    /**
+     * Spherical linear interpolation
+     *
+     * # Return value
+     *
+     * The rotation at `amount` along the shortest arc from the rotation to
+     * `other`
+     */
+    fn slerp(&self, other: &Self, amount: T) -> Self;
+}
+
+/**
+ * A 2-dimensional rotation in the form of a unit complex number
+ *
+ * Composing rotations only involves a complex multiplication, and the
+ * rotation can be cheaply renormalized, so unlike repeatedly multiplying
+ * `Mat2`s, accumulated rotations do not drift into shears and scales.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `v` - the complex number, where `v.x` is the cosine of the angle and
+ *         `v.y` is the sine of the angle
+ */
+#[deriving(Eq)]
+pub struct Rot2<T> { v: Vec2<T> }
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rot2<T> {
+    /**
+     * Construct the rotation from the real and imaginary parts of a unit
+     * complex number
+     *
+     * # Arguments
+     *
+     * * `c` - the cosine of the angle
+     * * `s` - the sine of the angle
+     */
+    #[inline(always)]
+    fn new(c: T, s: T) -> Rot2<T> {
+        Rot2 { v: BaseVec2::new(c, s) }
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation by zero radians
+     */
+    #[inline(always)]
+    fn identity() -> Rot2<T> {
+        Rot2::new(one(), zero())
+    }
+
+    /**
+     * Construct a counter-clockwise rotation by an angle
+     */
+    #[inline(always)]
+    fn from_angle(radians: T) -> Rot2<T> {
+        Rot2::new(cos(radians), sin(radians))
+    }
+
+    /**
+     * Construct a counter-clockwise rotation by an angle typed as radians
+     */
+    #[inline(always)]
+    fn from_rad(angle: Rad<T>) -> Rot2<T> {
+        Rot2::from_angle(*angle)
+    }
+
+    /**
+     * Construct a counter-clockwise rotation by an angle typed as degrees
+     */
+    #[inline(always)]
+    fn from_deg(angle: Deg<T>) -> Rot2<T> {
+        Rot2::from_rad(angle.to_rad())
+    }
+
+    /**
+     * # Return value
+     *
+     * The angle of the rotation in radians, in the range `(-π, π]`
+     */
+    #[inline(always)]
+    fn angle(&self) -> T {
+        atan2(self.v.y, self.v.x)
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation that applies `other` followed by the rotation
+     */
+    #[inline(always)]
+    fn concat(&self, other: &Rot2<T>) -> Rot2<T> {
+        Rot2 { v: self.v.complex_mul(&other.v) }
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation in the opposite direction, ie: the complex conjugate
+     */
+    #[inline(always)]
+    fn inverse(&self) -> Rot2<T> {
+        Rot2::new(self.v.x, -self.v.y)
+    }
+
+    /**
+     * # Return value
+     *
+     * The rotation scaled back to unit length, removing any drift that has
+     * been accumulated by composing many rotations
+     */
+    #[inline(always)]
+    fn normalize(&self) -> Rot2<T> {
+        Rot2 { v: self.v.normalize() }
+    }
+
+    /**
+     * Spherical linear interpolation
+     *
+     * # Return value
+     *
+     * The rotation at `amount` along the shortest arc from the rotation to
+     * `other`
+     */
+    #[inline(always)]
+    fn slerp(&self, other: &Rot2<T>, amount: T) -> Rot2<T> {
+        let delta = self.inverse().concat(other).angle();
+        self.concat(&Rot2::from_angle(delta * amount))
+    }
+
+    /**
+     * # Return value
+     *
+     * The vector rotated by the rotation
+     */
+    #[inline(always)]
+    fn rotate(&self, vec: &Vec2<T>) -> Vec2<T> {
+        self.v.complex_mul(vec)
+    }
+
+    /**
+     * Convert the rotation to a 2 x 2 rotation matrix
+     *
+     * `Mat2::from_angle` rotates clockwise, so the result is the same as
+     * `Mat2::from_angle(-self.angle())`.
+     */
+    #[inline(always)]
+    fn to_mat2(&self) -> Mat2<T> {
+        BaseMat2::new( self.v.x, self.v.y,
+                      -self.v.y, self.v.x)
+    }
+
+    /**
+     * Convert the rotation to a 3 x 3 matrix that acts on 2-dimensional
+     * homogeneous coordinates
+     */
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> {
+        self.to_mat2().to_mat3()
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rotation2<T> for Rot2<T> {
+    #[inline(always)]
+    fn identity() -> Rot2<T> { Rot2::identity() }
+
+    #[inline(always)]
+    fn rotate_vector(&self, vec: &Vec2<T>) -> Vec2<T> { self.rotate(vec) }
+
+    #[inline(always)]
+    fn concat(&self, other: &Rot2<T>) -> Rot2<T> { self.concat(other) }
+
+    #[inline(always)]
+    fn invert(&self) -> Rot2<T> { self.inverse() }
+
+    #[inline(always)]
+    fn to_mat2(&self) -> Mat2<T> { self.to_mat2() }
+
+    #[inline(always)]
+    fn slerp(&self, other: &Rot2<T>, amount: T) -> Rot2<T> { self.slerp(other, amount) }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T>> FuzzyEq<T> for Rot2<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Rot2<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Rot2<T>, epsilon: &T) -> bool {
+        self.v.fuzzy_eq_eps(&other.v, epsilon)
+    }
+}
+
+macro_rules! rot2_type(
+    ($name:ident <$T:ty>) => (
+        pub impl $name {
+            #[inline(always)] fn new(c: $T, s: $T) -> $name { Rot2::new(c, s) }
+            #[inline(always)] fn identity() -> $name { Rot2::identity() }
+            #[inline(always)] fn from_angle(radians: $T) -> $name { Rot2::from_angle(radians) }
+            #[inline(always)] fn from_rad(angle: Rad<$T>) -> $name { Rot2::from_rad(angle) }
+            #[inline(always)] fn from_deg(angle: Deg<$T>) -> $name { Rot2::from_deg(angle) }
+
+            #[inline(always)] fn dim() -> uint { 2 }
+            #[inline(always)] fn size_of() -> uint { sys::size_of::<$name>() }
+        }
+    );
+)
+
+// GLSL-style type aliases. These are not present in the GLSL specification,
+// but they roughly follow the same nomenclature.
+
+/// a single-precision floating-point 2-dimensional rotation
+pub type rot2  = Rot2<f32>;
+/// a double-precision floating-point 2-dimensional rotation
+pub type drot2 = Rot2<f64>;
+
+rot2_type!(rot2<f32>)
+rot2_type!(drot2<f64>)
+
+// Rust-style type aliases
+pub type Rot2f   = Rot2<float>;
+pub type Rot2f32 = Rot2<f32>;
+pub type Rot2f64 = Rot2<f64>;
+
+rot2_type!(Rot2f<float>)
+rot2_type!(Rot2f32<f32>)
+rot2_type!(Rot2f64<f64>)
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rotation2<T> for Mat2<T> {
+    #[inline(always)]
+    fn identity() -> Mat2<T> { BaseMat::identity() }
+
+    #[inline(always)]
+    fn rotate_vector(&self, vec: &Vec2<T>) -> Vec2<T> { self.mul_v(vec) }
+
+    #[inline(always)]
+    fn concat(&self, other: &Mat2<T>) -> Mat2<T> { self.mul_m(other) }
+
+    #[inline(always)]
+    fn invert(&self) -> Mat2<T> { self.transpose() }
+
+    #[inline(always)]
+    fn to_mat2(&self) -> Mat2<T> { *self }
+
+    #[inline(always)]
+    fn slerp(&self, other: &Mat2<T>, amount: T) -> Mat2<T> {
+        let a = Rot2::new(self[0][0], self[0][1]);
+        let b = Rot2::new(other[0][0], other[0][1]);
+        a.slerp(&b, amount).to_mat2()
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rotation3<T> for Mat3<T> {
+    #[inline(always)]
+    fn identity() -> Mat3<T> { BaseMat::identity() }
+
+    #[inline(always)]
+    fn rotate_vector(&self, vec: &Vec3<T>) -> Vec3<T> { self.mul_v(vec) }
+
+    #[inline(always)]
+    fn concat(&self, other: &Mat3<T>) -> Mat3<T> { self.mul_m(other) }
+
+    #[inline(always)]
+    fn invert(&self) -> Mat3<T> { self.transpose() }
+
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> { *self }
+
+    #[inline(always)]
+    fn slerp(&self, other: &Mat3<T>, amount: T) -> Mat3<T> {
+        self.to_quat().slerp_shortest(&other.to_quat(), amount).to_mat3()
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rotation3<T> for Quat<T> {
+    #[inline(always)]
+    fn identity() -> Quat<T> { Quat::identity() }
+
+    #[inline(always)]
+    fn rotate_vector(&self, vec: &Vec3<T>) -> Vec3<T> { self.mul_v(vec) }
+
+    #[inline(always)]
+    fn concat(&self, other: &Quat<T>) -> Quat<T> { self.mul_q(other) }
+
+    #[inline(always)]
+    fn invert(&self) -> Quat<T> { self.conjugate() }
+
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> { self.to_mat3() }
+
+    #[inline(always)]
+    fn slerp(&self, other: &Quat<T>, amount: T) -> Quat<T> { self.slerp_shortest(other, amount) }
+}
+
+/**
+ * An orthonormal basis describing a 3-dimensional rotation
+ *
+ * This is a `Mat3` that can only be constructed from rotations, so unlike a
+ * general matrix it can always be inverted by transposing it.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `mat` - the rotation matrix
+ */
+#[deriving(Eq)]
+pub struct Basis3<T> { priv mat: Mat3<T> }
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Basis3<T> {
+    #[inline(always)]
+    fn from_angle_axis(radians: T, axis: &Vec3<T>) -> Basis3<T> {
+        Basis3 { mat: BaseMat3::from_angle_axis(radians, axis) }
+    }
+
+    #[inline(always)]
+    fn from_rad_axis(angle: Rad<T>, axis: &Vec3<T>) -> Basis3<T> {
+        Basis3 { mat: BaseMat3::from_rad_axis(angle, axis) }
+    }
+
+    #[inline(always)]
+    fn from_deg_axis(angle: Deg<T>, axis: &Vec3<T>) -> Basis3<T> {
+        Basis3 { mat: BaseMat3::from_deg_axis(angle, axis) }
+    }
+
+    #[inline(always)]
+    fn from_quat(quat: &Quat<T>) -> Basis3<T> {
+        Basis3 { mat: quat.normalize().to_mat3() }
+    }
+
+    #[inline(always)]
+    fn look_at(dir: &Vec3<T>, up: &Vec3<T>) -> Basis3<T> {
+        Basis3 { mat: BaseMat3::look_at(dir, up) }
+    }
+
+    /**
+     * # Return value
+     *
+     * The basis converted to a quaternion
+     */
+    #[inline(always)]
+    fn to_quat(&self) -> Quat<T> {
+        self.mat.to_quat()
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Rotation3<T> for Basis3<T> {
+    #[inline(always)]
+    fn identity() -> Basis3<T> { Basis3 { mat: BaseMat::identity() } }
+
+    #[inline(always)]
+    fn rotate_vector(&self, vec: &Vec3<T>) -> Vec3<T> { self.mat.mul_v(vec) }
+
+    #[inline(always)]
+    fn concat(&self, other: &Basis3<T>) -> Basis3<T> { Basis3 { mat: self.mat.mul_m(&other.mat) } }
+
+    #[inline(always)]
+    fn invert(&self) -> Basis3<T> { Basis3 { mat: self.mat.transpose() } }
+
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> { self.mat }
+
+    #[inline(always)]
+    fn slerp(&self, other: &Basis3<T>, amount: T) -> Basis3<T> {
+        Basis3 { mat: self.mat.slerp(&other.mat, amount) }
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FuzzyEq<T> for Basis3<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Basis3<T>) -> bool {
+        self.mat.fuzzy_eq(&other.mat)
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Basis3<T>, epsilon: &T) -> bool {
+        self.mat.fuzzy_eq_eps(&other.mat, epsilon)
+    }
+}
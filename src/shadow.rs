@@ -0,0 +1,191 @@
+use core::num::{Zero, One};
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use camera::look_at;
+use frustum::Frustum;
+use mat::{BaseMat, Mat4};
+use projection::{perspective, orthographic};
+use vec::{Vec3, BaseVec3, Vec4, BaseVec4, NumVec};
+
+/**
+ * A face of a cube map, in the order of the OpenGL cube map targets, ie:
+ * `GL_TEXTURE_CUBE_MAP_POSITIVE_X + face as uint`
+ */
+#[deriving(Eq)]
+pub enum CubeFace {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+/**
+ * Create the view matrix for rendering a face of a cube map centred on `eye`
+ *
+ * The up vectors follow the OpenGL cube map convention, so the rendered image
+ * can be used as the face without flipping it. The faces are rendered with
+ * `cube_face_projection`.
+ */
+pub fn cube_face_view<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(face: CubeFace, eye: &Vec3<T>) -> Mat4<T> {
+    let _0: T = num::cast(0);
+    let _1: T = num::cast(1);
+
+    let (dir, up): (Vec3<T>, Vec3<T>) = match face {
+        PositiveX => (BaseVec3::new( _1,  _0,  _0), BaseVec3::new(_0, -_1,  _0)),
+        NegativeX => (BaseVec3::new(-_1,  _0,  _0), BaseVec3::new(_0, -_1,  _0)),
+        PositiveY => (BaseVec3::new( _0,  _1,  _0), BaseVec3::new(_0,  _0,  _1)),
+        NegativeY => (BaseVec3::new( _0, -_1,  _0), BaseVec3::new(_0,  _0, -_1)),
+        PositiveZ => (BaseVec3::new( _0,  _0,  _1), BaseVec3::new(_0, -_1,  _0)),
+        NegativeZ => (BaseVec3::new( _0,  _0, -_1), BaseVec3::new(_0, -_1,  _0)),
+    };
+
+    look_at(eye, &eye.add_v(&dir), &up)
+}
+
+/**
+ * # Return value
+ *
+ * The view matrices of the six faces of a cube map centred on `eye`, in the
+ * order of `CubeFace`
+ */
+pub fn cube_face_views<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(eye: &Vec3<T>) -> [Mat4<T>, ..6] {
+    [cube_face_view(PositiveX, eye),
+     cube_face_view(NegativeX, eye),
+     cube_face_view(PositiveY, eye),
+     cube_face_view(NegativeY, eye),
+     cube_face_view(PositiveZ, eye),
+     cube_face_view(NegativeZ, eye)]
+}
+
+/**
+ * # Return value
+ *
+ * The projection matrix for rendering a face of a cube map, which has a field
+ * of view of 90 degrees and a square aspect ratio
+ */
+#[inline(always)]
+pub fn cube_face_projection<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(near: T, far: T) -> Mat4<T> {
+    perspective(num::cast(90), num::cast(1), near, far)
+}
+
+/**
+ * Compute the distances that split a view frustum into cascades for cascaded
+ * shadow maps, using the practical split scheme
+ *
+ * The practical split scheme blends between logarithmic splits, which give
+ * each cascade the same ratio of far to near distances, and uniform splits.
+ *
+ * - [Parallel-Split Shadow Maps on Programmable GPUs]
+ *   (http://http.developer.nvidia.com/GPUGems3/gpugems3_ch10.html)
+ *
+ * # Arguments
+ *
+ * * `near`   - the distance of the near plane of the view
+ * * `far`    - the distance of the far plane of the view, or of the furthest
+ *              shadow
+ * * `count`  - the number of cascades
+ * * `lambda` - the weight of the logarithmic splits, from `0` for uniform
+ *              splits to `1` for logarithmic splits
+ *
+ * # Return value
+ *
+ * The `count + 1` distances bounding the cascades, starting with `near` and
+ * ending with `far`. Cascade `i` covers the distances from element `i` to
+ * element `i + 1`.
+ */
+pub fn cascade_splits<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(near: T, far: T, count: uint, lambda: T) -> ~[T] {
+    let _1: T = num::cast(1);
+    let n: T = num::cast(count);
+
+    let mut splits = ~[near];
+
+    for uint::range(1, count) |i| {
+        let f = num::cast::<uint, T>(i) / n;
+
+        let logarithmic = near * pow(far / near, f);
+        let uniform = near + (far - near) * f;
+
+        splits.push(lambda * logarithmic + (_1 - lambda) * uniform);
+    }
+
+    splits.push(far);
+    splits
+}
+
+/**
+ * Fit an orthographic projection tightly around a set of points, as seen from
+ * a light
+ *
+ * The depth range only covers the points, so objects between the light and
+ * the points will not cast shadows onto them unless the near plane is moved
+ * towards the light.
+ *
+ * # Arguments
+ *
+ * * `points`     - the points to enclose, such as the corners of a cascade.
+ *                  There must be at least one.
+ * * `light_view` - the view matrix of the light
+ *
+ * # Return value
+ *
+ * The projection matrix, to be used as `proj * light_view`
+ */
+pub fn fit_orthographic<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(points: &[Vec3<T>], light_view: &Mat4<T>) -> Mat4<T> {
+    let _1: T = num::cast(1);
+
+    let to_light = |p: &Vec3<T>| -> Vec4<T> {
+        light_view.mul_v(&BaseVec4::new(p.x, p.y, p.z, _1))
+    };
+
+    let first = to_light(&points[0]);
+    let mut min: Vec3<T> = BaseVec3::new(first.x, first.y, first.z);
+    let mut max = min;
+
+    for points.each |p| {
+        let q = to_light(p);
+
+        if q.x < min.x { min.x = q.x; }
+        if q.y < min.y { min.y = q.y; }
+        if q.z < min.z { min.z = q.z; }
+        if q.x > max.x { max.x = q.x; }
+        if q.y > max.y { max.y = q.y; }
+        if q.z > max.z { max.z = q.z; }
+    }
+
+    // the light looks down its negative z axis
+    orthographic(min.x, max.x, min.y, max.y, -max.z, -min.z)
+}
+
+/**
+ * Create the light projection matrices for cascaded shadow maps
+ *
+ * Note: the fovy parameter should be specified in degrees.
+ *
+ * # Arguments
+ *
+ * * `view`        - the view matrix of the camera
+ * * `fovy`        - the vertical field of view of the camera
+ * * `aspectRatio` - the aspect ratio of the camera
+ * * `splits`      - the distances bounding the cascades, as returned by
+ *                   `cascade_splits`
+ * * `light_view`  - the view matrix of the light
+ *
+ * # Return value
+ *
+ * The projection matrix of each cascade, fitted with `fit_orthographic`
+ */
+pub fn cascade_projections<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>>(view: &Mat4<T>, fovy: T, aspectRatio: T, splits: &[T], light_view: &Mat4<T>) -> ~[Mat4<T>] {
+    let mut projs = ~[];
+
+    for uint::range(1, splits.len()) |i| {
+        let slice = perspective(fovy, aspectRatio, splits[i - 1], splits[i]);
+        let frustum: Frustum<T> = Frustum::from_mat4(&slice.mul_m(view));
+        let corners = frustum.corners();
+        projs.push(fit_orthographic(&corners, light_view));
+    }
+
+    projs
+}
@@ -0,0 +1,177 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::{BaseMat, Mat3, BaseMat3, Mat4, BaseMat4};
+use vec::{Vec3, BaseVec3, AffineVec};
+
+/**
+ * A stack of matrices with the semantics of the now deprecated OpenGL matrix
+ * stack functions, such as [glPushMatrix, glPopMatrix]
+ * (http://www.opengl.org/sdk/docs/man2/xhtml/glPushMatrix.xml) and
+ * [glMultMatrix](http://www.opengl.org/sdk/docs/man2/xhtml/glMultMatrix.xml).
+ *
+ * As in OpenGL, the transformations post-multiply the current matrix, so the
+ * transformation specified last is the first one applied to vertices.
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the elements of the matrices. Should be a floating
+ *         point type.
+ */
+pub struct MatrixStack<T> {
+    priv stack: ~[Mat4<T>],
+}
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> MatrixStack<T> {
+    /**
+     * Construct a stack containing a single identity matrix
+     */
+    #[inline(always)]
+    fn new() -> MatrixStack<T> {
+        MatrixStack { stack: ~[BaseMat::identity()] }
+    }
+
+    /**
+     * # Return value
+     *
+     * The number of matrices on the stack. This is always at least one.
+     */
+    #[inline(always)]
+    fn depth(&self) -> uint {
+        self.stack.len()
+    }
+
+    /**
+     * # Return value
+     *
+     * The current matrix, ie: the matrix at the top of the stack
+     */
+    #[inline(always)]
+    fn top(&self) -> Mat4<T> {
+        self.stack[self.stack.len() - 1]
+    }
+
+    /**
+     * Push a copy of the current matrix onto the stack
+     *
+     * This is the equivalent of `glPushMatrix`.
+     */
+    #[inline(always)]
+    fn push(&mut self) {
+        let m = self.top();
+        self.stack.push(m);
+    }
+
+    /**
+     * Replace the current matrix with the one below it on the stack
+     *
+     * This is the equivalent of `glPopMatrix`.
+     *
+     * # Return value
+     *
+     * * `Ok(())` - if the matrix was popped
+     * * `Err(msg)` - if the current matrix is the last one on the stack, in
+     *   which case the stack is left unchanged
+     */
+    #[inline(always)]
+    fn pop(&mut self) -> Result<(), ~str> {
+        if self.stack.len() <= 1 {
+            Err(~"stack underflow: can not pop the last matrix")
+        } else {
+            self.stack.pop();
+            Ok(())
+        }
+    }
+
+    /**
+     * Replace the current matrix with the identity matrix
+     *
+     * This is the equivalent of `glLoadIdentity`.
+     */
+    #[inline(always)]
+    fn load_identity(&mut self) {
+        self.load_matrix(&BaseMat::identity());
+    }
+
+    /**
+     * Replace the current matrix with `m`
+     *
+     * This is the equivalent of `glLoadMatrix`.
+     */
+    #[inline(always)]
+    fn load_matrix(&mut self, m: &Mat4<T>) {
+        let i = self.stack.len() - 1;
+        self.stack[i] = *m;
+    }
+
+    /**
+     * Post-multiply the current matrix by `m`, ie: `top = top * m`
+     *
+     * This is the equivalent of `glMultMatrix`.
+     */
+    #[inline(always)]
+    fn mult_matrix(&mut self, m: &Mat4<T>) {
+        let i = self.stack.len() - 1;
+        self.stack[i] = self.stack[i].mul_m(m);
+    }
+
+    /**
+     * Pre-multiply the current matrix by `m`, ie: `top = m * top`
+     *
+     * There is no equivalent in OpenGL. This applies `m` after all of the
+     * transformations already on the current matrix.
+     */
+    #[inline(always)]
+    fn pre_mult_matrix(&mut self, m: &Mat4<T>) {
+        let i = self.stack.len() - 1;
+        self.stack[i] = m.mul_m(&self.stack[i]);
+    }
+
+    /**
+     * Post-multiply the current matrix by a translation
+     *
+     * This is the equivalent of `glTranslate`.
+     */
+    #[inline(always)]
+    fn translate(&mut self, x: T, y: T, z: T) {
+        self.mult_matrix(&BaseMat4::new( one(), zero(), zero(), zero(),
+                                        zero(),  one(), zero(), zero(),
+                                        zero(), zero(),  one(), zero(),
+                                             x,      y,      z,  one()));
+    }
+
+    /**
+     * Post-multiply the current matrix by a counter-clockwise rotation around
+     * the axis `(x, y, z)`, which does not need to be normalized
+     *
+     * This is the equivalent of `glRotate`, and likewise `angle` should be
+     * specified in degrees.
+     */
+    #[inline(always)]
+    fn rotate(&mut self, angle: T, x: T, y: T, z: T) {
+        let axis: Vec3<T> = BaseVec3::new(x, y, z);
+        let m: Mat3<T> = BaseMat3::from_angle_axis(radians(angle), &axis.normalize());
+        self.mult_matrix(&m.to_mat4());
+    }
+
+    /**
+     * Post-multiply the current matrix by a scale along each axis
+     *
+     * This is the equivalent of `glScale`.
+     */
+    #[inline(always)]
+    fn scale(&mut self, x: T, y: T, z: T) {
+        self.mult_matrix(&BaseMat4::new(     x, zero(), zero(), zero(),
+                                        zero(),      y, zero(), zero(),
+                                        zero(), zero(),      z, zero(),
+                                        zero(), zero(), zero(),  one()));
+    }
+}
+
+// Rust-style type aliases
+pub type MatrixStackf   = MatrixStack<float>;
+pub type MatrixStackf32 = MatrixStack<f32>;
+pub type MatrixStackf64 = MatrixStack<f64>;
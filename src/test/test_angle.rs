@@ -0,0 +1,109 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+use numeric::float::Float::{pi, frac_pi_2};
+
+use angle::*;
+use mat::*;
+use projection::*;
+use quat::*;
+use rot::*;
+use vec::*;
+
+#[test]
+fn test_angle_conversion() {
+    assert!(Deg(180.0).to_rad().fuzzy_eq(&Rad(pi::<f64>())));
+    assert!(Rad(frac_pi_2::<f64>()).to_deg().fuzzy_eq(&Deg(90.0)));
+    assert!(Deg(45.0).to_deg() == Deg(45.0));
+    assert!(Rad(0.5).to_rad() == Rad(0.5));
+
+    let full: Rad<f64> = Angle::full_turn();
+    let half: Deg<f64> = Angle::half_turn();
+    assert!(full.fuzzy_eq(&Rad(2.0 * pi())));
+    assert!(half == Deg(180.0));
+}
+
+#[test]
+fn test_angle_arithmetic() {
+    assert!(Deg(30.0) + Deg(60.0) == Deg(90.0));
+    assert!(Deg(30.0) - Deg(60.0) == Deg(-30.0));
+    assert!(Rad(0.5) * 4.0 == Rad(2.0));
+    assert!(Rad(2.0) / 4.0 == Rad(0.5));
+    assert!(-Deg(10.0) == Deg(-10.0));
+
+    assert!(Deg(10.0) < Deg(20.0));
+    assert!(Rad(-1.0) < Rad(0.0));
+}
+
+#[test]
+fn test_angle_normalize() {
+    assert!(Deg(370.0).normalize().fuzzy_eq(&Deg(10.0)));
+    assert!(Deg(-10.0).normalize().fuzzy_eq(&Deg(350.0)));
+    assert!(Deg(360.0).normalize() == Deg(0.0));
+    assert!(Deg(-720.0).normalize() == Deg(0.0));
+
+    assert!(Deg(190.0).normalize_signed().fuzzy_eq(&Deg(-170.0)));
+    assert!(Deg(-190.0).normalize_signed().fuzzy_eq(&Deg(170.0)));
+    assert!(Deg(180.0).normalize_signed() == Deg(180.0));
+    assert!(Deg(-180.0).normalize_signed() == Deg(180.0));
+
+    assert!(Rad(-frac_pi_2::<f64>()).normalize().fuzzy_eq(&Rad(3.0 * frac_pi_2())));
+    assert!(Rad(5.0 * frac_pi_2::<f64>()).normalize_signed().fuzzy_eq(&Rad(frac_pi_2())));
+    assert!(Rad(-5.0 * frac_pi_2::<f64>()).normalize_signed().fuzzy_eq(&Rad(-frac_pi_2())));
+
+    // the result is never a full turn, even when rounding would give one
+    let r = Rad(-1.0e-20).normalize();
+    assert!(*r >= 0.0 && *r < 2.0 * pi::<f64>());
+}
+
+#[test]
+fn test_angle_trig() {
+    assert!(Deg(90.0).sin().fuzzy_eq(&1.0));
+    assert!(Deg(60.0).cos().fuzzy_eq(&0.5));
+    assert!(Deg(45.0).tan().fuzzy_eq(&1.0));
+    assert!(Rad(frac_pi_2::<f64>()).sin().fuzzy_eq(&1.0));
+    assert!(Rad(0.0).cos() == 1.0);
+}
+
+#[test]
+fn test_angle_constructors() {
+    let axis = dvec3::new(1.0, 2.0, 3.0).normalize();
+
+    assert!(dmat2::from_rad(Rad(0.5)) == dmat2::from_angle(0.5));
+    assert!(dmat3::from_rad_x(Rad(0.5)) == dmat3::from_angle_x(0.5));
+    assert!(dmat3::from_rad_y(Deg(30.0).to_rad()) == dmat3::from_angle_y(radians(30.0)));
+    assert!(dmat3::from_rad_z(Rad(0.5)) == dmat3::from_angle_z(0.5));
+    assert!(dmat3::from_rad_xyz(Rad(0.1), Rad(0.2), Rad(0.3)) == dmat3::from_angle_xyz(0.1, 0.2, 0.3));
+    assert!(dmat3::from_rad_axis(Rad(0.5), &axis) == dmat3::from_angle_axis(0.5, &axis));
+
+    assert!(dquat::from_rad_axis(Rad(0.5), &axis) == dquat::from_angle_axis(0.5, &axis));
+    assert!(drot2::from_rad(Rad(0.5)) == drot2::from_angle(0.5));
+
+    // the single axis quaternions agree with the matrices
+    assert!(dquat::from_rad_x(Rad(0.5)).to_mat3().fuzzy_eq(&dmat3::from_angle_x(0.5)));
+    assert!(dquat::from_rad_y(Rad(0.5)).to_mat3().fuzzy_eq(&dmat3::from_angle_y(0.5)));
+    assert!(dquat::from_rad_z(Rad(0.5)).to_mat3().fuzzy_eq(&dmat3::from_angle_z(0.5)));
+
+    assert!(perspective_rad(Deg(60.0).to_rad(), 1.5, 1.0, 100.0).fuzzy_eq(&perspective(60.0, 1.5, 1.0, 100.0)));
+}
+
+#[test]
+fn test_angle_constructors_deg() {
+    let axis = dvec3::new(1.0, 2.0, 3.0).normalize();
+
+    assert!(dmat2::from_deg(Deg(30.0)).fuzzy_eq(&dmat2::from_angle(radians(30.0))));
+    assert!(dmat3::from_deg_x(Deg(30.0)).fuzzy_eq(&dmat3::from_angle_x(radians(30.0))));
+    assert!(dmat3::from_deg_y(Deg(30.0)).fuzzy_eq(&dmat3::from_angle_y(radians(30.0))));
+    assert!(dmat3::from_deg_z(Deg(30.0)).fuzzy_eq(&dmat3::from_angle_z(radians(30.0))));
+    assert!(dmat3::from_deg_xyz(Deg(10.0), Deg(20.0), Deg(30.0)).fuzzy_eq(&dmat3::from_angle_xyz(radians(10.0), radians(20.0), radians(30.0))));
+    assert!(dmat3::from_deg_axis(Deg(30.0), &axis).fuzzy_eq(&dmat3::from_angle_axis(radians(30.0), &axis)));
+
+    assert!(dquat::from_deg_x(Deg(30.0)).fuzzy_eq(&dquat::from_angle_x(radians(30.0))));
+    assert!(dquat::from_deg_y(Deg(30.0)).fuzzy_eq(&dquat::from_angle_y(radians(30.0))));
+    assert!(dquat::from_deg_z(Deg(30.0)).fuzzy_eq(&dquat::from_angle_z(radians(30.0))));
+    assert!(dquat::from_deg_xyz(Deg(10.0), Deg(20.0), Deg(30.0)).fuzzy_eq(&dquat::from_angle_xyz(radians(10.0), radians(20.0), radians(30.0))));
+    assert!(dquat::from_deg_axis(Deg(30.0), &axis).fuzzy_eq(&dquat::from_angle_axis(radians(30.0), &axis)));
+    assert!(drot2::from_deg(Deg(30.0)).fuzzy_eq(&drot2::from_angle(radians(30.0))));
+
+    // the field of view of `perspective` is in degrees
+    assert!(perspective_deg(Deg(60.0), 1.5, 1.0, 100.0).fuzzy_eq(&perspective(60.0, 1.5, 1.0, 100.0)));
+}
@@ -0,0 +1,195 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use angle::*;
+use camera::*;
+use mat::*;
+use projection::*;
+use quat::*;
+use transform::*;
+use vec::*;
+
+#[test]
+fn test_look_at() {
+    let up = dvec3::new(0.0, 1.0, 0.0);
+
+    let view = look_at(&dvec3::new(0.0, 0.0, 5.0), &dvec3::new(0.0, 0.0, 0.0), &up);
+    let translation = Transform3::new(dvec3::from_value(1.0), dquat::identity(), dvec3::new(0.0, 0.0, -5.0));
+    assert!(view.fuzzy_eq(&translation.to_mat4()));
+
+    // looking down the positive x axis
+    let view = look_at(&dvec3::new(1.0, 2.0, 3.0), &dvec3::new(4.0, 2.0, 3.0), &up);
+    assert!(view.mul_v(&dvec4::new(4.0, 2.0, 3.0, 1.0)).fuzzy_eq(&dvec4::new(0.0, 0.0, -3.0, 1.0)));
+    assert!(view.mul_v(&dvec4::new(1.0, 3.0, 3.0, 1.0)).fuzzy_eq(&dvec4::new(0.0, 1.0, 0.0, 1.0)));
+    assert!(view.mul_v(&dvec4::new(1.0, 2.0, 4.0, 1.0)).fuzzy_eq(&dvec4::new(1.0, 0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn test_fps_camera() {
+    let up = dvec3::new(0.0, 1.0, 0.0);
+    let mut camera = FpsCamera::new(dvec3::new(0.0, 0.0, 0.0), Rad(0.0), Rad(0.0));
+
+    assert!(camera.view().fuzzy_eq(&dmat4::identity()));
+
+    // turn to the left
+    camera.rotate(Deg(90.0).to_rad(), Rad(0.0));
+    assert!(camera.forward().fuzzy_eq(&dvec3::new(-1.0, 0.0, 0.0)));
+
+    // turning past a half turn wraps the yaw
+    camera.rotate(Deg(180.0).to_rad(), Rad(0.0));
+    assert!(camera.yaw.fuzzy_eq(&Deg(-90.0).to_rad()));
+    assert!(camera.forward().fuzzy_eq(&dvec3::new(1.0, 0.0, 0.0)));
+
+    camera.translate(&dvec3::new(0.0, 0.0, -2.0));
+    assert!(camera.position.fuzzy_eq(&dvec3::new(2.0, 0.0, 0.0)));
+    assert!(camera.view().fuzzy_eq(&look_at(&camera.position, &dvec3::new(3.0, 0.0, 0.0), &up)));
+
+    // look up, but not past the pitch limit
+    camera.rotate(Rad(0.0), Deg(30.0).to_rad());
+    assert!(camera.forward().y.fuzzy_eq(&0.5));
+    camera.rotate(Rad(0.0), Deg(100.0).to_rad());
+    assert!(camera.pitch.fuzzy_eq(&Deg(89.0).to_rad()));
+    camera.rotate(Rad(0.0), Deg(-200.0).to_rad());
+    assert!(camera.pitch.fuzzy_eq(&Deg(-89.0).to_rad()));
+}
+
+#[test]
+fn test_orbit_camera() {
+    let up = dvec3::new(0.0, 1.0, 0.0);
+    let target = dvec3::new(1.0, 0.0, 0.0);
+    let mut camera = OrbitCamera::new(target, 5.0, Rad(0.0), Rad(0.0));
+
+    assert!(camera.eye().fuzzy_eq(&dvec3::new(1.0, 0.0, 5.0)));
+    assert!(camera.view().fuzzy_eq(&look_at(&camera.eye(), &target, &up)));
+
+    camera.rotate(Deg(90.0).to_rad(), Rad(0.0));
+    assert!(camera.eye().fuzzy_eq(&dvec3::new(6.0, 0.0, 0.0)));
+    assert!(camera.view().fuzzy_eq(&look_at(&camera.eye(), &target, &up)));
+
+    // raise the camera above the target
+    camera.rotate(Rad(0.0), Deg(30.0).to_rad());
+    assert!(camera.eye().y.fuzzy_eq(&2.5));
+    assert!(camera.view().fuzzy_eq(&look_at(&camera.eye(), &target, &up)));
+
+    camera.zoom(0.5);
+    assert!(camera.eye().sub_v(&target).length().fuzzy_eq(&2.5));
+
+    // pan to the right, which is the negative z direction
+    camera.rotate(Rad(0.0), Deg(-30.0).to_rad());
+    camera.pan(1.0, 0.0);
+    assert!(camera.target.fuzzy_eq(&dvec3::new(1.0, 0.0, -1.0)));
+}
+
+#[test]
+fn test_arcball_camera() {
+    let mut camera = ArcballCamera::new(dvec3::new(0.0, 0.0, 0.0), 5.0, dquat::identity());
+    let start = camera.view();
+
+    assert!(arcball_point(&dvec2::new(0.0, 0.0)) == dvec3::new(0.0, 0.0, 1.0));
+    assert!(arcball_point(&dvec2::new(0.0, 2.0)) == dvec3::new(0.0, 1.0, 0.0));
+
+    // dragging to the right by 45 degrees on the ball rotates the scene by 90
+    // degrees, so the camera moves to the left
+    camera.rotate(&dvec2::new(0.0, 0.0), &dvec2::new(sqrt(0.5), 0.0));
+    assert!(camera.eye().fuzzy_eq(&dvec3::new(-5.0, 0.0, 0.0)));
+
+    // the point facing the camera has moved to the right
+    let p = camera.view().mul_v(&dvec4::new(0.0, 0.0, 1.0, 1.0));
+    assert!(p.fuzzy_eq(&dvec4::new(1.0, 0.0, -5.0, 1.0)));
+
+    // dragging back returns to the start
+    camera.rotate(&dvec2::new(sqrt(0.5), 0.0), &dvec2::new(0.0, 0.0));
+    assert!(camera.view().fuzzy_eq(&start));
+
+    // dragging from the centre to the edge rotates the scene by 180 degrees
+    camera.rotate(&dvec2::new(0.0, 0.0), &dvec2::new(0.0, 1.0));
+    assert!(camera.eye().fuzzy_eq(&dvec3::new(0.0, 0.0, -5.0)));
+}
+
+#[test]
+fn test_pick_ray() {
+    let view = look_at(&dvec3::new(0.0, 0.0, 5.0), &dvec3::new(0.0, 0.0, 0.0), &dvec3::new(0.0, 1.0, 0.0));
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 400.0);
+    let centre = dvec2::new(400.0, 200.0);
+    let corner = dvec2::new(800.0, 400.0);
+
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+
+    let ray = pick_ray(&centre, &view, &proj, &viewport).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(0.0, 0.0, 4.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0)));
+
+    let ray = pick_ray(&corner, &view, &proj, &viewport).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(2.0, 1.0, 4.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(2.0, 1.0, -1.0).normalize()));
+    assert!(ray.at(sqrt(6.0)).fuzzy_eq(&dvec3::new(4.0, 2.0, 3.0)));
+
+    // the far plane at infinity
+    let ray = pick_ray(&corner, &view, &perspective_infinite(90.0, 2.0, 1.0), &viewport).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(2.0, 1.0, 4.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(2.0, 1.0, -1.0).normalize()));
+
+    // the sign of `w` at the far plane is arbitrary when it is at infinity,
+    // and must not affect the direction
+    let infinite = perspective_infinite(90.0, 2.0, 1.0);
+    let turned = dmat3::from_angle_y(radians(30.0)).to_mat4().mul_m(&view);
+    for [centre, corner, dvec2::new(123.0, 321.0)].each |win| {
+        let finite = pick_ray(win, &turned, &perspective(90.0, 2.0, 1.0, 1.0e6), &viewport).unwrap();
+        let ray = pick_ray(win, &turned, &infinite, &viewport).unwrap();
+        assert!(ray.direction.fuzzy_eq(&finite.direction));
+        let ray = pick_ray(win, &turned, &-infinite, &viewport).unwrap();
+        assert!(ray.direction.fuzzy_eq(&finite.direction));
+    }
+
+    let proj = orthographic(-4.0, 4.0, -2.0, 2.0, 1.0, 10.0);
+
+    let ray = pick_ray(&corner, &view, &proj, &viewport).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(4.0, 2.0, 4.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0)));
+
+    assert!(pick_ray(&corner, &view, &dmat4::zero(), &viewport).is_none());
+}
+
+#[test]
+fn test_thin_lens_ray() {
+    let view = look_at(&dvec3::new(0.0, 0.0, 5.0), &dvec3::new(0.0, 0.0, 0.0), &dvec3::new(0.0, 1.0, 0.0));
+    let viewport = dvec4::new(0.0, 0.0, 800.0, 400.0);
+    let centre = dvec2::new(400.0, 200.0);
+    let corner = dvec2::new(800.0, 400.0);
+
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+
+    // the centre of the lens gives the pinhole ray, starting at the eye
+    let ray = thin_lens_ray(&corner, &view, &proj, &viewport, 0.2, 10.0, &dvec2::new(0.0, 0.0)).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(0.0, 0.0, 5.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(2.0, 1.0, -1.0).normalize()));
+
+    // other points on the lens converge on the plane of focus
+    let ray = thin_lens_ray(&centre, &view, &proj, &viewport, 0.2, 10.0, &dvec2::new(1.0, 0.0)).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(0.1, 0.0, 5.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(-0.1, 0.0, -10.0).normalize()));
+
+    let ray = thin_lens_ray(&corner, &view, &proj, &viewport, 0.2, 10.0, &dvec2::new(0.0, 1.0)).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(0.0, 0.1, 5.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(20.0, 9.9, -10.0).normalize()));
+
+    let proj = orthographic(-4.0, 4.0, -2.0, 2.0, 1.0, 10.0);
+
+    let ray = thin_lens_ray(&corner, &view, &proj, &viewport, 2.0, 10.0, &dvec2::new(1.0, 0.0)).unwrap();
+    assert!(ray.origin.fuzzy_eq(&dvec3::new(5.0, 2.0, 5.0)));
+    assert!(ray.direction.fuzzy_eq(&dvec3::new(-1.0, 0.0, -10.0).normalize()));
+
+    assert!(thin_lens_ray(&corner, &dmat4::zero(), &proj, &viewport, 2.0, 10.0, &dvec2::new(1.0, 0.0)).is_none());
+}
+
+#[test]
+fn test_concentric_disk() {
+    let h = sqrt(0.5);
+
+    assert!(concentric_disk(&dvec2::new(0.5, 0.5)) == dvec2::new(0.0, 0.0));
+    assert!(concentric_disk(&dvec2::new(1.0, 0.5)).fuzzy_eq(&dvec2::new(1.0, 0.0)));
+    assert!(concentric_disk(&dvec2::new(0.5, 1.0)).fuzzy_eq(&dvec2::new(0.0, 1.0)));
+    assert!(concentric_disk(&dvec2::new(1.0, 1.0)).fuzzy_eq(&dvec2::new(h, h)));
+    assert!(concentric_disk(&dvec2::new(0.0, 0.0)).fuzzy_eq(&dvec2::new(-h, -h)));
+    assert!(concentric_disk(&dvec2::new(0.75, 0.5)).fuzzy_eq(&dvec2::new(0.5, 0.0)));
+}
@@ -0,0 +1,76 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use frustum::*;
+use mat::*;
+use projection::*;
+use vec::*;
+
+#[test]
+fn test_frustum_planes() {
+    let f: Frustum<f64> = Frustum::from_mat4(&perspective(90.0, 1.0, 1.0, 100.0));
+    let h = sqrt(0.5);
+
+    assert!(f.left.fuzzy_eq(&dvec4::new(h, 0.0, -h, 0.0)));
+    assert!(f.right.fuzzy_eq(&dvec4::new(-h, 0.0, -h, 0.0)));
+    assert!(f.bottom.fuzzy_eq(&dvec4::new(0.0, h, -h, 0.0)));
+    assert!(f.top.fuzzy_eq(&dvec4::new(0.0, -h, -h, 0.0)));
+    assert!(f.near.fuzzy_eq(&dvec4::new(0.0, 0.0, -1.0, -1.0)));
+    assert!(f.far.fuzzy_eq(&dvec4::new(0.0, 0.0, 1.0, 100.0)));
+
+    // the far plane of an infinite projection contains every point
+    let f: Frustum<f64> = Frustum::from_mat4(&perspective_infinite(90.0, 1.0, 1.0));
+    assert!(f.far == dvec4::new(0.0, 0.0, 0.0, 1.0));
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, -1.0e9)) == Inside);
+}
+
+#[test]
+fn test_frustum_containment() {
+    let f: Frustum<f64> = Frustum::from_mat4(&perspective(90.0, 1.0, 1.0, 100.0));
+
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, -10.0)) == Inside);
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, -0.5)) == Outside);
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, -101.0)) == Outside);
+    assert!(f.contains_point(&dvec3::new(20.0, 0.0, -10.0)) == Outside);
+
+    assert!(f.contains_sphere(&dvec3::new(0.0, 0.0, -10.0), 1.0) == Inside);
+    assert!(f.contains_sphere(&dvec3::new(0.0, 0.0, -10.0), 20.0) == Intersecting);
+    assert!(f.contains_sphere(&dvec3::new(0.0, 0.0, -0.5), 1.0) == Intersecting);
+    assert!(f.contains_sphere(&dvec3::new(0.0, 0.0, 10.0), 1.0) == Outside);
+
+    assert!(f.contains_aabb(&dvec3::new(-1.0, -1.0, -11.0), &dvec3::new(1.0, 1.0, -9.0)) == Inside);
+    assert!(f.contains_aabb(&dvec3::new(-1.0, -1.0, -2.0), &dvec3::new(1.0, 1.0, 0.0)) == Intersecting);
+    assert!(f.contains_aabb(&dvec3::new(10.0, -1.0, -3.0), &dvec3::new(12.0, 1.0, -2.0)) == Outside);
+
+    let f: Frustum<f64> = Frustum::from_mat4(&orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0));
+
+    assert!(f.contains_point(&dvec3::new(0.5, 0.5, -5.0)) == Inside);
+    assert!(f.contains_point(&dvec3::new(1.5, 0.0, -5.0)) == Outside);
+    assert!(f.contains_sphere(&dvec3::new(1.0, 0.0, -5.0), 0.5) == Intersecting);
+    assert!(f.contains_aabb(&dvec3::new(-2.0, -2.0, -20.0), &dvec3::new(2.0, 2.0, 0.0)) == Intersecting);
+}
+
+#[test]
+fn test_frustum_corners() {
+    let f: Frustum<f64> = Frustum::from_mat4(&perspective(90.0, 1.0, 1.0, 100.0));
+    let c = f.corners();
+
+    assert!(c[0].fuzzy_eq(&dvec3::new(-1.0, -1.0, -1.0)));
+    assert!(c[3].fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0)));
+    assert!(c[5].fuzzy_eq(&dvec3::new(100.0, -100.0, -100.0)));
+    assert!(c[6].fuzzy_eq(&dvec3::new(-100.0, 100.0, -100.0)));
+
+    // in world space, with the camera at (0, 0, 5)
+    let view = dmat4::new(1.0, 0.0,  0.0, 0.0,
+                          0.0, 1.0,  0.0, 0.0,
+                          0.0, 0.0,  1.0, 0.0,
+                          0.0, 0.0, -5.0, 1.0);
+    let f: Frustum<f64> = Frustum::from_mat4(&perspective(90.0, 1.0, 1.0, 100.0).mul_m(&view));
+
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, -5.0)) == Inside);
+    assert!(f.contains_point(&dvec3::new(0.0, 0.0, 4.5)) == Outside);
+    assert!(f.corners()[0].fuzzy_eq(&dvec3::new(-1.0, -1.0, 4.0)));
+
+    let f: Frustum<f64> = Frustum::from_mat4(&orthographic(-1.0, 1.0, -2.0, 2.0, 1.0, 10.0));
+    assert!(f.corners()[7].fuzzy_eq(&dvec3::new(1.0, 2.0, -10.0)));
+}
@@ -0,0 +1,121 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use hierarchy::*;
+use mat::*;
+use vec::*;
+
+fn translation(x: f64, y: f64, z: f64) -> dmat4 {
+    dmat4::new(1.0, 0.0, 0.0, 0.0,
+               0.0, 1.0, 0.0, 0.0,
+               0.0, 0.0, 1.0, 0.0,
+                 x,   y,   z, 1.0)
+}
+
+#[test]
+fn test_hierarchy() {
+    let mut h: Hierarchy<f64> = Hierarchy::new();
+
+    let root  = h.add(translation(1.0, 0.0, 0.0), None);
+    let child = h.add(dmat3::from_angle_z(radians(90.0)).to_mat4(), Some(root));
+    let leaf  = h.add(translation(0.0, 2.0, 0.0), Some(child));
+
+    assert!(h.len() == 3);
+    assert!(h.parent(root).is_none());
+    assert!(h.parent(leaf) == Some(child));
+    assert!(h.is_dirty(leaf));
+
+    h.update();
+    assert!(!h.is_dirty(leaf));
+    assert!(h.world(root) == translation(1.0, 0.0, 0.0));
+    assert!(h.world(leaf).mul_v(&dvec4::new(0.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(-1.0, 0.0, 0.0, 1.0)));
+
+    // changing a parent moves its descendants
+    h.set_local(root, translation(5.0, 0.0, 0.0));
+    assert!(h.is_dirty(root));
+    h.update();
+    assert!(h.world(leaf).mul_v(&dvec4::new(0.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(3.0, 0.0, 0.0, 1.0)));
+}
+
+#[test]
+fn test_hierarchy_deep_chain() {
+    let mut h: Hierarchy<f64> = Hierarchy::new();
+
+    let mut node = h.add(translation(1.0, 0.0, 0.0), None);
+    for 999.times {
+        node = h.add(translation(1.0, 0.0, 0.0), Some(node));
+    }
+
+    h.update();
+    assert!(h.world(node).fuzzy_eq(&translation(1000.0, 0.0, 0.0)));
+
+    h.set_local(0, translation(2.0, 0.0, 0.0));
+    h.update();
+    assert!(h.world(node).fuzzy_eq(&translation(1001.0, 0.0, 0.0)));
+}
+
+#[test]
+fn test_hierarchy_reparent() {
+    let mut h: Hierarchy<f64> = Hierarchy::new();
+
+    let a = h.add(translation(1.0, 0.0, 0.0), None);
+    let b = h.add(dmat3::from_angle_y(radians(45.0)).to_mat4().mul_m(&translation(0.0, 3.0, 0.0)), None);
+    let c = h.add(translation(0.0, 0.0, 2.0), Some(a));
+    let d = h.add(translation(0.0, 1.0, 0.0), Some(c));
+
+    h.update();
+    let world_c = h.world(c);
+    let world_d = h.world(d);
+
+    // the world transformations are preserved, and children follow
+    assert!(h.set_parent(c, Some(b)).is_ok());
+    assert!(h.parent(c) == Some(b));
+    h.update();
+    assert!(h.world(c).fuzzy_eq(&world_c));
+    assert!(h.world(d).fuzzy_eq(&world_d));
+
+    // changing the new parent moves the nodes below it
+    h.set_local(b, translation(0.0, 0.0, 0.0));
+    h.update();
+    assert!(h.world(d).fuzzy_eq(&h.local(c).mul_m(&h.local(d))));
+
+    // `a` now comes before its parent `d` in the arena, but is still updated
+    // after it
+    assert!(h.set_parent(a, Some(d)).is_ok());
+    h.set_local(d, translation(0.0, 4.0, 0.0));
+    h.update();
+    assert!(h.world(a).fuzzy_eq(&h.world(d).mul_m(&h.local(a))));
+
+    assert!(h.set_parent(c, None).is_ok());
+    h.update();
+    assert!(h.local(c).fuzzy_eq(&h.world(c)));
+}
+
+#[test]
+fn test_hierarchy_cycles() {
+    let mut h: Hierarchy<f64> = Hierarchy::new();
+
+    let a = h.add(dmat4::identity(), None);
+    let b = h.add(dmat4::identity(), Some(a));
+    let c = h.add(dmat4::identity(), Some(b));
+
+    assert!(h.set_parent(a, Some(a)).is_err());
+    assert!(h.set_parent(a, Some(c)).is_err());
+    assert!(h.set_parent(b, Some(c)).is_err());
+    assert!(h.parent(a).is_none());
+    assert!(h.parent(b) == Some(a));
+
+    assert!(h.set_parent(c, Some(a)).is_ok());
+
+    // a parent without an inverse can not preserve the world transformation
+    let z = h.add(dmat4::zero(), None);
+    assert!(h.set_parent(c, Some(z)).is_err());
+    assert!(h.parent(c) == Some(a));
+
+    // failing leaves the pending changes for the next update
+    h.set_local(a, translation(1.0, 0.0, 0.0));
+    assert!(h.set_parent(b, Some(c)).is_err());
+    assert!(h.set_parent(c, Some(z)).is_err());
+    assert!(h.is_dirty(a));
+    assert!(h.is_dirty(z));
+}
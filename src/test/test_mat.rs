@@ -477,6 +477,10 @@ fn test_mat4() {
     assert!(c.is_invertible());
     
     assert!(mat4::from_value(6.0).is_diagonal());
+    
+    assert!(a.to_mat3() == mat3::new(1.0, 5.0,  9.0,
+                                     2.0, 6.0, 10.0,
+                                     3.0, 7.0, 11.0));
 }
 
 fn test_mat4_mut() {
@@ -0,0 +1,87 @@
+use std::cmp::FuzzyEq;
+
+use mat::*;
+use point::*;
+use projection::*;
+use vec::*;
+
+#[test]
+fn test_point2() {
+    let a = Point2::new(1.0, 2.0);
+    let b = Point2::new(4.0, 6.0);
+    let v = dvec2::new(3.0, 4.0);
+
+    assert!(b.sub_p(&a) == v);
+    assert!(a.add_v(&v) == b);
+    assert!(b.sub_v(&v) == a);
+    assert!(a.distance(&b) == 5.0);
+    assert!(a.distance2(&b) == 25.0);
+
+    let o: Point2<f64> = Point::origin();
+    assert!(o == Point2::new(0.0, 0.0));
+    let p: Point2<f64> = Point::from_vec(&v);
+    assert!(p == o.add_v(&v));
+    assert!(a.to_vec() == a.sub_p(&o));
+
+    assert!(a.to_homogeneous() == dvec3::new(1.0, 2.0, 1.0));
+    assert!(v.to_homogeneous() == dvec3::new(3.0, 4.0, 0.0));
+
+    let (p, w): (Point2<f64>, f64) = FromHomogeneous::from_homogeneous_with_w(&dvec3::new(2.0, 4.0, 2.0));
+    assert!(p == a);
+    assert!(w == 2.0);
+}
+
+#[test]
+fn test_point3() {
+    let a = Point3::new(1.0, 2.0, 3.0);
+    let b = Point3::new(3.0, 5.0, 9.0);
+    let v = dvec3::new(2.0, 3.0, 6.0);
+
+    assert!(b.sub_p(&a) == v);
+    assert!(a.add_v(&v) == b);
+    assert!(b.sub_v(&v) == a);
+    assert!(a.distance(&b) == 7.0);
+    assert!(a.distance2(&b) == 49.0);
+
+    let o: Point3<f64> = Point::origin();
+    assert!(o == Point3::new(0.0, 0.0, 0.0));
+    let p: Point3<f64> = Point::from_vec(&v);
+    assert!(p == o.add_v(&v));
+    assert!(a.to_vec() == a.sub_p(&o));
+
+    assert!(a.to_homogeneous() == dvec4::new(1.0, 2.0, 3.0, 1.0));
+    assert!(v.to_homogeneous() == dvec4::new(2.0, 3.0, 6.0, 0.0));
+
+    // the round trip through homogeneous coordinates
+    let p: Point3<f64> = FromHomogeneous::from_homogeneous(&a.to_homogeneous().mul_t(2.0));
+    assert!(p == a);
+    let p: Option<Point3<f64>> = FromHomogeneous::from_homogeneous_checked(&v.to_homogeneous());
+    assert!(p.is_none());
+
+    assert!(a.fuzzy_eq(&Point3::new(1.0, 2.0, 3.0 + 1.0e-10)));
+    assert!(!a.fuzzy_eq(&b));
+}
+
+#[test]
+fn test_mat4_transform() {
+    // a rotation of 90 degrees about the z axis, followed by a translation
+    let m = dmat4::new( 0.0, 1.0, 0.0, 0.0,
+                       -1.0, 0.0, 0.0, 0.0,
+                        0.0, 0.0, 1.0, 0.0,
+                        5.0, 6.0, 7.0, 1.0);
+
+    // only points are translated
+    assert!(m.transform_point(&Point3::new(1.0, 0.0, 0.0)).fuzzy_eq(&Point3::new(5.0, 7.0, 7.0)));
+    assert!(m.transform_vector(&dvec3::new(1.0, 0.0, 0.0)).fuzzy_eq(&dvec3::new(0.0, 1.0, 0.0)));
+
+    // the vector between two points is transformed as a vector
+    let a = Point3::new(1.0, 2.0, 3.0);
+    let b = Point3::new(-2.0, 0.5, 4.0);
+    let v = m.transform_point(&b).sub_p(&m.transform_point(&a));
+    assert!(v.fuzzy_eq(&m.transform_vector(&b.sub_p(&a))));
+
+    // projected points are divided by w
+    let proj = perspective(90.0, 1.0, 1.0, 100.0);
+    assert!(proj.transform_point(&Point3::new(1.0, 1.0, -1.0)).fuzzy_eq(&Point3::new(1.0, 1.0, -1.0)));
+    assert!(proj.transform_point(&Point3::new(100.0, -100.0, -100.0)).fuzzy_eq(&Point3::new(1.0, -1.0, 1.0)));
+}
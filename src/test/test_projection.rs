@@ -0,0 +1,459 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::*;
+use projection::*;
+use vec::*;
+
+/// Transform a point in eye space to normalized device coordinates
+fn to_ndc(m: &dmat4, x: f64, y: f64, z: f64) -> dvec3 {
+    let clip = m.mul_v(&dvec4::new(x, y, z, 1.0));
+    dvec3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w)
+}
+
+/// The normalized device depth of a direction, ie: a point at infinity
+fn depth_at_infinity(m: &dmat4) -> f64 {
+    let clip = m.mul_v(&dvec4::new(0.0, 0.0, -1.0, 0.0));
+    clip.z / clip.w
+}
+
+#[test]
+fn test_perspective() {
+    let m = perspective(90.0, 2.0, 1.0, 100.0);
+
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0)));
+    assert!(to_ndc(&m, 0.0, 0.0, -100.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 1.0)));
+    assert!(to_ndc(&m, 2.0, 1.0, -1.0).fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0)));
+}
+
+#[test]
+fn test_perspective_infinite() {
+    let m = perspective_infinite(90.0, 2.0, 1.0);
+
+    assert!(m == frustum_infinite(-2.0, 2.0, -1.0, 1.0, 1.0));
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0)));
+    assert!(to_ndc(&m, 2.0, 1.0, -1.0).fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0)));
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0e9).z < 1.0);
+    assert!(depth_at_infinity(&m).fuzzy_eq(&1.0));
+
+    // the limit of the finite projection
+    assert!(m.fuzzy_eq_eps(&perspective(90.0, 2.0, 1.0, 1.0e9), &0.00001));
+}
+
+#[test]
+fn test_perspective_reversed_z() {
+    let m = perspective_reversed_z(90.0, 2.0, 1.0, 100.0);
+
+    assert!(m == frustum_reversed_z(-2.0, 2.0, -1.0, 1.0, 1.0, 100.0));
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 1.0)));
+    assert!(to_ndc(&m, 0.0, 0.0, -100.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 0.0)));
+    assert!(to_ndc(&m, 200.0, 100.0, -100.0).fuzzy_eq(&dvec3::new(1.0, 1.0, 0.0)));
+
+    // depth decreases monotonically with distance
+    assert!(to_ndc(&m, 0.0, 0.0, -10.0).z < to_ndc(&m, 0.0, 0.0, -5.0).z);
+}
+
+#[test]
+fn test_perspective_infinite_reversed_z() {
+    let m = perspective_infinite_reversed_z(90.0, 2.0, 1.0);
+
+    assert!(m == frustum_infinite_reversed_z(-2.0, 2.0, -1.0, 1.0, 1.0));
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 1.0)));
+    assert!(to_ndc(&m, 0.0, 0.0, -1.0e9).z > 0.0);
+    assert!(depth_at_infinity(&m).fuzzy_eq(&0.0));
+
+    assert!(m.fuzzy_eq_eps(&perspective_reversed_z(90.0, 2.0, 1.0, 1.0e9), &0.00001));
+}
+
+#[test]
+fn test_frustum_off_axis() {
+    let m = frustum_infinite(-1.0, 3.0, -2.0, 1.0, 2.0);
+
+    assert!(to_ndc(&m, -1.0, -2.0, -2.0).fuzzy_eq(&dvec3::new(-1.0, -1.0, -1.0)));
+    assert!(to_ndc(&m, 3.0, 1.0, -2.0).fuzzy_eq(&dvec3::new(1.0, 1.0, -1.0)));
+
+    let r = frustum_reversed_z(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0);
+    assert!(to_ndc(&r, 15.0, 5.0, -10.0).fuzzy_eq(&dvec3::new(1.0, 1.0, 0.0)));
+}
+
+#[test]
+fn test_clip_space() {
+    let gl = ClipSpace::opengl();
+    let vk = ClipSpace::vulkan();
+    let dx = ClipSpace::direct3d();
+
+    let m = perspective(90.0, 2.0, 1.0, 100.0);
+    assert!(perspective_for(90.0, 2.0, 1.0, 100.0, &gl) == m);
+    assert!(frustum_for(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0, &gl) == frustum(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0));
+    assert!(orthographic_for(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0, &gl) == orthographic(-1.0, 1.0, -1.0, 1.0, 1.0, 10.0));
+
+    // Vulkan maps depth to [0, 1] and flips the y axis
+    let v = perspective_for(90.0, 2.0, 1.0, 100.0, &vk);
+    assert!(to_ndc(&v, 0.0, 0.0, -1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 0.0)));
+    assert!(to_ndc(&v, 0.0, 0.0, -100.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 1.0)));
+    assert!(to_ndc(&v, 2.0, 1.0, -1.0).fuzzy_eq(&dvec3::new(1.0, -1.0, 0.0)));
+
+    // Direct3D looks down the positive z axis
+    let d = perspective_for(90.0, 2.0, 1.0, 100.0, &dx);
+    assert!(to_ndc(&d, 0.0, 0.0, 1.0).fuzzy_eq(&dvec3::new(0.0, 0.0, 0.0)));
+    assert!(to_ndc(&d, 2.0, 1.0, 100.0).fuzzy_eq(&dvec3::new(0.01, 0.01, 1.0)));
+
+    let o = orthographic_for(-2.0, 2.0, -1.0, 1.0, 1.0, 11.0, &dx);
+    assert!(to_ndc(&o, 2.0, 1.0, 1.0).fuzzy_eq(&dvec3::new(1.0, 1.0, 0.0)));
+    assert!(to_ndc(&o, -2.0, -1.0, 11.0).fuzzy_eq(&dvec3::new(-1.0, -1.0, 1.0)));
+}
+
+#[test]
+fn test_clip_space_conversion() {
+    let gl = ClipSpace::opengl();
+    let vk = ClipSpace::vulkan();
+    let dx = ClipSpace::direct3d();
+    let mt = ClipSpace::metal();
+
+    let m = frustum(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0);
+
+    assert!(clip_space_conversion::<f64>(&gl, &gl) == dmat4::identity());
+    assert!(clip_space_conversion::<f64>(&vk, &gl).mul_m(&clip_space_conversion(&gl, &vk)) == dmat4::identity());
+    assert!(clip_space_conversion::<f64>(&dx, &mt) == dmat4::identity());
+
+    assert!(convert_projection(&m, &gl, &vk).fuzzy_eq(&frustum_for(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0, &vk)));
+    assert!(convert_projection(&m, &gl, &dx).fuzzy_eq(&frustum_for(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0, &dx)));
+    assert!(convert_projection(&convert_projection(&m, &gl, &dx), &dx, &gl).fuzzy_eq(&m));
+}
+
+#[test]
+fn test_projection_inverse() {
+    let p = perspective(60.0, 1.5, 0.5, 50.0);
+    assert!(perspective_inverse(60.0, 1.5, 0.5, 50.0).mul_m(&p).fuzzy_eq(&dmat4::identity()));
+    assert!(perspective_inverse(60.0, 1.5, 0.5, 50.0).fuzzy_eq(&p.inverse().unwrap()));
+
+    let f = frustum(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0);
+    assert!(frustum_inverse(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0).mul_m(&f).fuzzy_eq(&dmat4::identity()));
+    assert!(f.mul_m(&frustum_inverse(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0)).fuzzy_eq(&dmat4::identity()));
+
+    let o = orthographic(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0);
+    assert!(orthographic_inverse(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0).mul_m(&o).fuzzy_eq(&dmat4::identity()));
+    assert!(o.mul_m(&orthographic_inverse(-1.0, 3.0, -2.0, 1.0, 2.0, 10.0)).fuzzy_eq(&dmat4::identity()));
+}
+
+#[test]
+fn test_project() {
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+    let view = dmat4::new(1.0, 0.0, 0.0, 0.0,
+                          0.0, 1.0, 0.0, 0.0,
+                          0.0, 0.0, 1.0, 0.0,
+                          0.0, 0.0, -5.0, 1.0);
+    let viewport = dvec4::new(10.0, 20.0, 800.0, 400.0);
+
+    // the centre of the near plane lands in the centre of the viewport
+    let centre = project(&dvec3::new(0.0, 0.0, 4.0), &view, &proj, &viewport).unwrap();
+    assert!(centre.fuzzy_eq(&dvec3::new(410.0, 220.0, 0.0)));
+
+    // the corner of the far plane
+    let corner = project(&dvec3::new(200.0, 100.0, -95.0), &view, &proj, &viewport).unwrap();
+    assert!(corner.fuzzy_eq(&dvec3::new(810.0, 420.0, 1.0)));
+
+    let obj = dvec3::new(1.0, -2.0, -3.0);
+    let win = project(&obj, &view, &proj, &viewport).unwrap();
+    assert!(unproject(&win, &view, &proj, &viewport).unwrap().fuzzy_eq(&obj));
+
+    let ortho = orthographic(-4.0, 4.0, -2.0, 2.0, 1.0, 10.0);
+    let win = project(&obj, &view, &ortho, &viewport).unwrap();
+    assert!(unproject(&win, &view, &ortho, &viewport).unwrap().fuzzy_eq(&obj));
+
+    // points in the plane of the camera can not be projected
+    assert!(project(&dvec3::new(1.0, 1.0, 5.0), &view, &proj, &viewport).is_none());
+
+    // singular matrices can not be unprojected
+    assert!(unproject(&win, &view, &dmat4::zero(), &viewport).is_none());
+}
+
+#[test]
+fn test_viewport() {
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+    let view = dmat4::new(1.0, 0.0, 0.0, 0.0,
+                          0.0, 1.0, 0.0, 0.0,
+                          0.0, 0.0, 1.0, 0.0,
+                          0.0, 0.0, -5.0, 1.0);
+    let viewport = dvec4::new(10.0, 20.0, 800.0, 400.0);
+
+    let vp = viewport_transform(&viewport);
+    assert!(vp.mul_v(&dvec4::new(-1.0, -1.0, -1.0, 1.0)).fuzzy_eq(&dvec4::new(10.0, 20.0, 0.0, 1.0)));
+    assert!(vp.mul_v(&dvec4::new(1.0, 1.0, 1.0, 1.0)).fuzzy_eq(&dvec4::new(810.0, 420.0, 1.0, 1.0)));
+    assert!(viewport_transform_inverse(&viewport).mul_m(&vp).fuzzy_eq(&dmat4::identity()));
+    assert!(vp.mul_m(&viewport_transform_inverse(&viewport)).fuzzy_eq(&dmat4::identity()));
+
+    // the same window coordinates as `project`
+    let obj = dvec3::new(1.0, -2.0, -3.0);
+    let win = vp.mul_m(&proj).mul_m(&view).mul_v(&dvec4::new(obj.x, obj.y, obj.z, 1.0));
+    let win = dvec3::new(win.x / win.w, win.y / win.w, win.z / win.w);
+    assert!(win.fuzzy_eq(&project(&obj, &view, &proj, &viewport).unwrap()));
+
+    // the region around the centre of the viewport fills clip space
+    let pick = pick_matrix(&dvec2::new(410.0, 220.0), &dvec2::new(80.0, 40.0), &viewport);
+    assert!(pick.mul_v(&dvec4::new(0.0, 0.0, 0.5, 1.0)).fuzzy_eq(&dvec4::new(0.0, 0.0, 0.5, 1.0)));
+    assert!(pick.mul_v(&dvec4::new(0.1, 0.1, 0.5, 1.0)).fuzzy_eq(&dvec4::new(1.0, 1.0, 0.5, 1.0)));
+
+    // a region around the bottom left corner of the viewport
+    let pick = pick_matrix(&dvec2::new(10.0, 20.0), &dvec2::new(80.0, 40.0), &viewport);
+    assert!(pick.mul_v(&dvec4::new(-2.0, -2.0, 0.0, 2.0)).fuzzy_eq(&dvec4::new(0.0, 0.0, 0.0, 2.0)));
+}
+
+#[test]
+fn test_pixel_orthographic() {
+    assert!(orthographic_2d(0.0, 800.0, 0.0, 400.0) == orthographic(0.0, 800.0, 0.0, 400.0, -1.0, 1.0));
+
+    let up: dmat4 = pixel_orthographic(800.0, 400.0, YUp);
+    assert!(up.mul_v(&dvec4::new(0.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(-1.0, -1.0, 0.0, 1.0)));
+    assert!(up.mul_v(&dvec4::new(800.0, 400.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(1.0, 1.0, 0.0, 1.0)));
+
+    let down: dmat4 = pixel_orthographic(800.0, 400.0, YDown);
+    assert!(down.mul_v(&dvec4::new(0.0, 0.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(-1.0, 1.0, 0.0, 1.0)));
+    assert!(down.mul_v(&dvec4::new(800.0, 400.0, 0.0, 1.0)).fuzzy_eq(&dvec4::new(1.0, -1.0, 0.0, 1.0)));
+
+    // the centre of the top left pixel lands on the centre of that pixel in
+    // window coordinates
+    let vp = viewport_transform(&dvec4::new(0.0, 0.0, 800.0, 400.0));
+    let win = vp.mul_m(&down).mul_v(&dvec4::new(0.5, 0.5, 0.0, 1.0));
+    assert!(win.fuzzy_eq(&dvec4::new(0.5, 399.5, 0.5, 1.0)));
+}
+
+#[test]
+fn test_decompose_projection() {
+    let m = perspective(60.0, 1.5, 0.5, 50.0);
+    let p = decompose_projection(&m).unwrap();
+
+    assert!(p.kind == Perspective);
+    assert!(p.fovy().fuzzy_eq(&60.0));
+    assert!(p.aspect_ratio().fuzzy_eq(&1.5));
+    assert!(p.near.fuzzy_eq(&0.5));
+    assert!(p.far.fuzzy_eq(&50.0));
+    assert!(p.offset().fuzzy_eq(&dvec2::new(0.0, 0.0)));
+    assert!(p.to_mat4().fuzzy_eq(&m));
+
+    let p = decompose_projection(&frustum(-1.0, 3.0, -2.0, 1.0, 2.0, 20.0)).unwrap();
+
+    assert!(p.kind == Perspective);
+    assert!(p.left.fuzzy_eq(&-1.0));
+    assert!(p.right.fuzzy_eq(&3.0));
+    assert!(p.bottom.fuzzy_eq(&-2.0));
+    assert!(p.top.fuzzy_eq(&1.0));
+    assert!(p.near.fuzzy_eq(&2.0));
+    assert!(p.far.fuzzy_eq(&20.0));
+    assert!(p.offset().fuzzy_eq(&dvec2::new(1.0, -0.5)));
+
+    let m = orthographic(-4.0, 2.0, -1.0, 3.0, 0.1, 10.0);
+    let p = decompose_projection(&m).unwrap();
+
+    assert!(p.kind == Orthographic);
+    assert!(p.fovy() == 0.0);
+    assert!(p.aspect_ratio().fuzzy_eq(&1.5));
+    assert!(p.offset().fuzzy_eq(&dvec2::new(-1.0, 1.0)));
+    assert!(p.near.fuzzy_eq(&0.1));
+    assert!(p.far.fuzzy_eq(&10.0));
+    assert!(p.to_mat4().fuzzy_eq(&m));
+
+    // not projections
+    assert!(decompose_projection(&dmat3::from_angle_z(0.5).to_mat4()).is_err());
+    assert!(decompose_projection(&perspective_infinite(60.0, 1.5, 0.5)).is_err());
+    assert!(decompose_projection(&dmat4::zero()).is_err());
+
+    let mut m = perspective(60.0, 1.5, 0.5, 50.0);
+    m.w.w = 1.0;
+    assert!(decompose_projection(&m).is_err());
+}
+
+#[test]
+fn test_decompose_projection_conventions() {
+    // reversed depth
+    assert!(decompose_projection(&perspective_reversed_z(60.0, 1.5, 1.0, 100.0)).is_err());
+    assert!(decompose_projection(&perspective_reversed_z(60.0, 1.5, 1.0, 1.5)).is_err());
+    assert!(decompose_projection(&perspective_infinite_reversed_z(60.0, 1.5, 1.0)).is_err());
+
+    // downwards y axis, and left handed eye space
+    let vulkan = perspective_for(60.0, 1.5, 1.0, 100.0, &ClipSpace::vulkan());
+    let direct3d = perspective_for(60.0, 1.5, 1.0, 100.0, &ClipSpace::direct3d());
+    assert!(decompose_projection(&vulkan).is_err());
+    assert!(decompose_projection(&direct3d).is_err());
+
+    for [ClipSpace::opengl(), ClipSpace::vulkan(), ClipSpace::direct3d(), ClipSpace::metal()].each |clip| {
+        let m = perspective_for(60.0, 1.5, 1.0, 100.0, clip);
+        let p = decompose_projection_for(&m, clip).unwrap();
+
+        assert!(p.kind == Perspective);
+        assert!(p.fovy().fuzzy_eq(&60.0));
+        assert!(p.aspect_ratio().fuzzy_eq(&1.5));
+        assert!(p.near.fuzzy_eq(&1.0));
+        assert!(p.far.fuzzy_eq(&100.0));
+        assert!(frustum_for(p.left, p.right, p.bottom, p.top, p.near, p.far, clip).fuzzy_eq(&m));
+
+        let m = orthographic_for(-4.0, 2.0, -1.0, 3.0, 0.1, 10.0, clip);
+        let p = decompose_projection_for(&m, clip).unwrap();
+
+        assert!(p.kind == Orthographic);
+        assert!(p.left.fuzzy_eq(&-4.0));
+        assert!(p.top.fuzzy_eq(&3.0));
+        assert!(p.near.fuzzy_eq(&0.1));
+        assert!(p.far.fuzzy_eq(&10.0));
+    }
+}
+
+#[test]
+fn test_generalized_perspective() {
+    let lower_left  = dvec3::new(-1.0, -1.0, -1.0);
+    let lower_right = dvec3::new( 1.0, -1.0, -1.0);
+    let upper_left  = dvec3::new(-1.0,  1.0, -1.0);
+
+    // an eye centred in front of the screen has a symmetric frustum
+    let p = generalized_perspective(&dvec3::new(0.0, 0.0, 0.0), &lower_left, &lower_right, &upper_left, 1.0, 100.0);
+    assert!(p.proj.fuzzy_eq(&perspective(90.0, 1.0, 1.0, 100.0)));
+    assert!(p.view.fuzzy_eq(&dmat4::identity()));
+
+    // moving the eye makes the frustum asymmetric
+    let p = generalized_perspective(&dvec3::new(0.5, 0.0, 0.0), &lower_left, &lower_right, &upper_left, 1.0, 100.0);
+    assert!(p.proj.fuzzy_eq(&frustum(-1.5, 0.5, -1.0, 1.0, 1.0, 100.0)));
+    assert!(p.view.fuzzy_eq(&dmat4::new( 1.0, 0.0, 0.0, 0.0,
+                                         0.0, 1.0, 0.0, 0.0,
+                                         0.0, 0.0, 1.0, 0.0,
+                                        -0.5, 0.0, 0.0, 1.0)));
+
+    // a screen on the wall to the left of the eye
+    let lower_left  = dvec3::new(-2.0, -1.0,  1.0);
+    let lower_right = dvec3::new(-2.0, -1.0, -1.0);
+    let upper_left  = dvec3::new(-2.0,  1.0,  1.0);
+
+    let p = generalized_perspective(&dvec3::new(0.0, 0.0, 0.0), &lower_left, &lower_right, &upper_left, 2.0, 100.0);
+    let m = p.proj.mul_m(&p.view);
+
+    assert!(p.proj.fuzzy_eq(&frustum(-1.0, 1.0, -1.0, 1.0, 2.0, 100.0)));
+    assert!(to_ndc(&m, -2.0, 0.0, 0.0).fuzzy_eq(&dvec3::new(0.0, 0.0, -1.0)));
+    assert!(to_ndc(&m, -2.0, 1.0, 1.0).fuzzy_eq(&dvec3::new(-1.0, 1.0, -1.0)));
+}
+
+#[test]
+fn test_stereo_pair() {
+    let (left, right) = stereo_pair(90.0, 2.0, 1.0, 100.0, 0.064, 10.0);
+
+    assert!(left.proj.fuzzy_eq(&frustum(-1.9968, 2.0032, -1.0, 1.0, 1.0, 100.0)));
+    assert!(right.proj.fuzzy_eq(&frustum(-2.0032, 1.9968, -1.0, 1.0, 1.0, 100.0)));
+    assert!(left.view.w.fuzzy_eq(&dvec4::new(0.032, 0.0, 0.0, 1.0)));
+    assert!(right.view.w.fuzzy_eq(&dvec4::new(-0.032, 0.0, 0.0, 1.0)));
+
+    // there is no parallax at the convergence distance
+    let l = to_ndc(&left.proj.mul_m(&left.view), 0.5, 0.25, -10.0);
+    let r = to_ndc(&right.proj.mul_m(&right.view), 0.5, 0.25, -10.0);
+    assert!(l.fuzzy_eq(&r));
+
+    // but there is beyond it
+    let l = to_ndc(&left.proj.mul_m(&left.view), 0.0, 0.0, -50.0);
+    let r = to_ndc(&right.proj.mul_m(&right.view), 0.0, 0.0, -50.0);
+    assert!(l.x < r.x);
+
+    // without any separation, both eyes see the symmetric projection
+    let (left, right) = stereo_pair(90.0, 2.0, 1.0, 100.0, 0.0, 10.0);
+    assert!(left.proj.fuzzy_eq(&perspective(90.0, 2.0, 1.0, 100.0)));
+    assert!(right.proj == left.proj);
+}
+
+#[test]
+fn test_oblique_near_plane() {
+    let proj = perspective(90.0, 1.0, 1.0, 100.0);
+
+    // a clipping plane parallel to the near plane
+    let m = oblique_near_plane(&proj, &dvec4::new(0.0, 0.0, -1.0, -5.0));
+
+    assert!(to_ndc(&m, 0.0, 0.0, -5.0).z.fuzzy_eq(&-1.0));
+    assert!(to_ndc(&m, 0.0, 0.0, -4.0).z < -1.0);
+    assert!(to_ndc(&m, 0.0, 0.0, -50.0).z > -1.0);
+    assert!(to_ndc(&m, 0.0, 0.0, -50.0).z < 1.0);
+    assert!(to_ndc(&m, 0.0, 0.0, -100.0).z.fuzzy_eq(&1.0));
+
+    // the x and y coordinates are unaffected
+    assert!(m.row(0) == proj.row(0));
+    assert!(m.row(1) == proj.row(1));
+    assert!(m.row(3) == proj.row(3));
+
+    // a tilted clipping plane through (0, 0, -5) and (0, 2, -3.5)
+    let m = oblique_near_plane(&proj, &dvec4::new(0.0, 0.6, -0.8, -4.0));
+
+    assert!(to_ndc(&m, 0.0, 0.0, -5.0).z.fuzzy_eq(&-1.0));
+    assert!(to_ndc(&m, 0.0, 2.0, -3.5).z.fuzzy_eq(&-1.0));
+    assert!(to_ndc(&m, 0.0, 0.0, -4.0).z < -1.0);
+    assert!(to_ndc(&m, 0.0, 2.0, -5.0).z > -1.0);
+
+    // the far plane passes through the corner of the original frustum that is
+    // furthest from the clipping plane
+    assert!(to_ndc(&m, 0.0, 100.0, -100.0).z.fuzzy_eq(&1.0));
+    assert!(to_ndc(&m, 0.0, -100.0, -100.0).z < 1.0);
+}
+
+#[test]
+fn test_halton() {
+    assert!(halton::<f64>(0, 2) == 0.0);
+    assert!(halton::<f64>(1, 2) == 0.5);
+    assert!(halton::<f64>(2, 2) == 0.25);
+    assert!(halton::<f64>(3, 2) == 0.75);
+    assert!(halton::<f64>(5, 2) == 0.625);
+
+    assert!(halton::<f64>(1, 3).fuzzy_eq(&(1.0 / 3.0)));
+    assert!(halton::<f64>(2, 3).fuzzy_eq(&(2.0 / 3.0)));
+    assert!(halton::<f64>(3, 3).fuzzy_eq(&(1.0 / 9.0)));
+
+    assert!(halton_2_3::<f64>(4).fuzzy_eq(&dvec2::new(0.125, 4.0 / 9.0)));
+}
+
+#[test]
+fn test_jitter() {
+    let offset = dvec2::new(0.1, -0.2);
+
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+    let m = jitter(&proj, &offset);
+    assert!(to_ndc(&m, 1.0, 2.0, -10.0).fuzzy_eq(&to_ndc(&proj, 1.0, 2.0, -10.0).add_v(&dvec3::new(0.1, -0.2, 0.0))));
+    assert!(to_ndc(&m, -3.0, 0.5, -50.0).fuzzy_eq(&to_ndc(&proj, -3.0, 0.5, -50.0).add_v(&dvec3::new(0.1, -0.2, 0.0))));
+
+    let proj = orthographic(-4.0, 4.0, -2.0, 2.0, 1.0, 10.0);
+    let m = jitter(&proj, &offset);
+    assert!(to_ndc(&m, 1.0, 1.0, -5.0).fuzzy_eq(&to_ndc(&proj, 1.0, 1.0, -5.0).add_v(&dvec3::new(0.1, -0.2, 0.0))));
+
+    // frame 0 uses element 1 of the sequence, (1/2, 1/3), which is centered
+    // horizontally and a sixth of a pixel down
+    let proj = perspective(90.0, 2.0, 1.0, 100.0);
+    let (unjittered, jittered) = jittered_pair(&proj, 0, 800.0, 600.0);
+    assert!(unjittered == proj);
+    assert!(jittered.fuzzy_eq(&jitter(&proj, &dvec2::new(0.0, -(1.0 / 3.0) / 600.0))));
+
+    // frame 1 uses element 2 of the sequence, (1/4, 2/3), which is a quarter
+    // of a pixel to the left and a sixth of a pixel up
+    let (unjittered, jittered) = jittered_pair(&proj, 1, 800.0, 600.0);
+    assert!(unjittered == proj);
+    assert!(jittered.fuzzy_eq(&jitter(&proj, &dvec2::new(-0.5 / 800.0, (1.0 / 3.0) / 600.0))));
+}
+
+#[test]
+fn test_physical_camera() {
+    // a full frame sensor with a 50mm lens
+    let camera = PhysicalCamera::new(50.0, 36.0, 24.0);
+    let fovy = 2.0 * degrees(atan2(12.0, 50.0));
+
+    assert!(camera.aspect_ratio().fuzzy_eq(&1.5));
+    assert!(camera.fovy(1.5).fuzzy_eq(&fovy));
+    assert!(camera.to_mat4(1.5, 1.0, 100.0).fuzzy_eq(&perspective(fovy, 1.5, 1.0, 100.0)));
+
+    // a wider viewport
+    assert!(camera.gate(2.0).fuzzy_eq(&dvec2::new(48.0, 24.0)));
+    assert!(camera.with_gate_fit(FitVertical).gate(2.0).fuzzy_eq(&dvec2::new(48.0, 24.0)));
+    assert!(camera.with_gate_fit(FitHorizontal).gate(2.0).fuzzy_eq(&dvec2::new(36.0, 18.0)));
+
+    // a narrower viewport
+    assert!(camera.gate(1.0).fuzzy_eq(&dvec2::new(36.0, 36.0)));
+    assert!(camera.with_gate_fit(FitVertical).gate(1.0).fuzzy_eq(&dvec2::new(24.0, 24.0)));
+    assert!(camera.with_gate_fit(FitHorizontal).gate(1.0).fuzzy_eq(&dvec2::new(36.0, 36.0)));
+
+    let camera = camera.with_gate_fit(FitHorizontal);
+    assert!(camera.fovy(2.0).fuzzy_eq(&(2.0 * degrees(atan2(9.0, 50.0)))));
+    assert!(camera.to_mat4(2.0, 1.0, 100.0).fuzzy_eq(&perspective(camera.fovy(2.0), 2.0, 1.0, 100.0)));
+
+    // shifting the lens makes the frustum asymmetric
+    let camera = camera.with_shift(0.5, -0.25);
+    assert!(camera.to_mat4(1.5, 1.0, 100.0).fuzzy_eq(&frustum(0.0, 0.72, -0.36, 0.12, 1.0, 100.0)));
+}
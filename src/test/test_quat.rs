@@ -42,8 +42,155 @@ fn test_quat_2() {
                                                        0f32,           0f32, 1f32)));
 }
 
+#[test]
+fn test_quat_from_angle() {
+    for [0.5, 1.2, -2.0, 3.0].each |&theta| {
+        assert!(dquat::from_angle_x(theta).to_mat3().fuzzy_eq(&dmat3::from_angle_x(theta)));
+        assert!(dquat::from_angle_y(theta).to_mat3().fuzzy_eq(&dmat3::from_angle_y(theta)));
+        assert!(dquat::from_angle_z(theta).to_mat3().fuzzy_eq(&dmat3::from_angle_z(theta)));
+    }
+
+    // the single axis rotations are unit quaternions
+    assert!(dquat::from_angle_x(1.2).magnitude().fuzzy_eq(&1.0));
+}
+
 #[test]
 fn test_quat_fuzzy_eq() {
     assert!(!quat::new(0.000001, 0.000001, 0.000001, 0.000001).fuzzy_eq(&quat::new(0.0, 0.0, 0.0, 0.0)));
     assert!(quat::new(0.0000001, 0.0000001, 0.0000001, 0.0000001).fuzzy_eq(&quat::new(0.0, 0.0, 0.0, 0.0)));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_quat_integrate() {
+    let q     = dquat::identity();
+    let omega = dvec3::new(0.0, 0.0, radians(90.0));
+
+    assert!(q.derivative(&omega) == dquat::new(0.0, 0.0, 0.0, radians(45.0)));
+
+    let r = q.integrate(&omega, 1.0);
+    assert!(r.fuzzy_eq(&dquat::from_angle_axis(radians(90.0), &dvec3::new(0.0, 0.0, 1.0))));
+    assert!(q.angular_velocity(&r, 1.0).fuzzy_eq(&omega));
+    assert!(q.angular_velocity(&-r, 1.0).fuzzy_eq(&omega));
+
+    // both variants agree for small steps
+    let a = q.integrate(&omega, 0.0001);
+    let b = q.integrate_linear(&omega, 0.0001);
+    assert!(a.fuzzy_eq_eps(&b, &0.00001));
+
+    assert!(q.integrate(&dvec3::zero(), 1.0) == q);
+}
+
+#[test]
+fn test_quat_average() {
+    let axis = dvec3::new(0.0, 0.0, 1.0);
+    let a = dquat::from_angle_axis(radians(30.0), &axis);
+    let b = dquat::from_angle_axis(radians(-30.0), &axis);
+    let c = dquat::from_angle_axis(radians(60.0), &axis);
+
+    assert!(dquat::average([a, b], [1.0, 1.0]).fuzzy_eq(&dquat::identity()));
+    assert!(dquat::average([a, c], [1.0, 0.0]).fuzzy_eq(&a));
+
+    // `-b` is the same rotation as `b`, so it must not change the result
+    assert!(dquat::average([a, -b], [1.0, 1.0]).fuzzy_eq(&dquat::identity()));
+
+    // the result does not depend on the order of the rotations
+    let abc = dquat::average([a, b, c], [1.0, 2.0, 3.0]);
+    let cba = dquat::average([c, b, a], [3.0, 2.0, 1.0]);
+    assert!(abc.fuzzy_eq(&cba) || abc.fuzzy_eq(&-cba));
+
+    assert!(dquat::average_nlerp([a, -b], [1.0, 1.0]).fuzzy_eq(&dquat::identity()));
+}
+
+#[test]
+#[should_fail]
+fn test_quat_average_empty() {
+    dquat::average([], []);
+}
+
+#[test]
+#[should_fail]
+fn test_quat_average_mismatched_weights() {
+    let a = dquat::identity();
+    dquat::average([a, a], [1.0]);
+}
+
+#[test]
+#[should_fail]
+fn test_quat_average_nlerp_empty() {
+    dquat::average_nlerp([], []);
+}
+
+#[test]
+#[should_fail]
+fn test_quat_average_zero_weights() {
+    let a = dquat::from_angle_axis(radians(30.0), &dvec3::new(0.0, 1.0, 0.0));
+    dquat::average([a, dquat::identity()], [0.0, 0.0]);
+}
+
+#[test]
+#[should_fail]
+fn test_quat_average_nlerp_zero_weights() {
+    let a = dquat::from_angle_axis(radians(30.0), &dvec3::new(0.0, 1.0, 0.0));
+    dquat::average_nlerp([a, dquat::identity()], [0.0, 0.0]);
+}
+
+#[test]
+fn test_quat_rotation_eq() {
+    let axis = dvec3::new(0.0, 1.0, 0.0);
+    let a = dquat::from_angle_axis(radians(30.0), &axis);
+    let b = dquat::from_angle_axis(radians(90.0), &axis);
+
+    assert!(!a.fuzzy_eq(&-a));
+    assert!(a.rotation_fuzzy_eq(&-a));
+    assert!(!a.rotation_fuzzy_eq(&b));
+
+    assert!(a.angle_to(&a).fuzzy_eq(&0.0));
+    assert!(a.angle_to(&-a).fuzzy_eq(&0.0));
+    assert!(a.angle_to(&b).fuzzy_eq(&radians(60.0)));
+    assert!(a.angle_to(&-b).fuzzy_eq(&radians(60.0)));
+
+    // small angles keep their precision in single precision
+    let c = quat::from_angle_axis(1.0e-4, &vec3::new(0.0, 1.0, 0.0));
+    assert!(quat::identity().angle_to(&c).fuzzy_eq_eps(&1.0e-4, &1.0e-8));
+    assert!(c.angle_to(&-quat::identity()).fuzzy_eq_eps(&1.0e-4, &1.0e-8));
+
+    assert!(a.canonicalize() == a);
+    assert!((-a).canonicalize() == a);
+    assert!(dquat::new(0.0, -1.0, 0.0, 0.0).canonicalize() == dquat::new(0.0, 1.0, 0.0, 0.0));
+    assert!(dquat::new(0.0, 0.0, 0.0, -1.0).canonicalize() == dquat::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_quat_mat_conversion() {
+    let q = dquat::from_angle_axis(radians(60.0), &dvec3::new(1.0, 2.0, 3.0).normalize());
+
+    assert!(q.to_mat4() == q.to_mat3().to_mat4());
+    assert!(q.to_mat3().to_quat().rotation_fuzzy_eq(&q));
+    assert!(q.to_mat4().to_quat().rotation_fuzzy_eq(&q));
+
+    // half turns take the branches for a negative trace
+    let x = dquat::from_angle_axis(radians(180.0), &dvec3::new(1.0, 0.0, 0.0));
+    let y = dquat::from_angle_axis(radians(180.0), &dvec3::new(0.0, 1.0, 0.0));
+    let z = dquat::from_angle_axis(radians(180.0), &dvec3::new(0.0, 0.0, 1.0));
+    assert!(x.to_mat3().to_quat().rotation_fuzzy_eq(&x));
+    assert!(y.to_mat3().to_quat().rotation_fuzzy_eq(&y));
+    assert!(z.to_mat3().to_quat().rotation_fuzzy_eq(&z));
+
+    // scale and translation are ignored
+    let mut m = q.to_mat3().mul_m(&dmat3::from_value(3.0)).to_mat4();
+    m.w = dvec4::new(1.0, 2.0, 3.0, 1.0);
+    assert!(m.to_quat().rotation_fuzzy_eq(&q));
+
+    // so are non-uniform scale and shear, as long as they are applied before
+    // the rotation and keep the first column along the x axis
+    let shear = dmat3::new( 2.0,   0.0, 0.0,
+                            0.5,   3.0, 0.0,
+                           0.25, -0.75, 0.5);
+    let m = q.to_mat3().mul_m(&shear).to_mat4();
+    assert!(m.to_quat().rotation_fuzzy_eq(&q));
+
+    // slightly non-orthonormal matrices still give unit quaternions
+    let n = q.to_mat3().add_m(&dmat3::from_value(0.001)).to_quat();
+    assert!(n.magnitude().fuzzy_eq(&1.0));
+    assert!(n.angle_to(&q) < 0.01);
+}
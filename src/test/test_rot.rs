@@ -0,0 +1,117 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::*;
+use quat::*;
+use rot::*;
+use vec::*;
+
+#[test]
+fn test_rot2() {
+    let a = drot2::from_angle(radians(30.0));
+    let b = drot2::from_angle(radians(60.0));
+    let v = dvec2::new(1.0, 0.0);
+
+    assert!(drot2::identity() == drot2::new(1.0, 0.0));
+    assert!(a.angle().fuzzy_eq(&radians(30.0)));
+
+    assert!(a.concat(&b).fuzzy_eq(&drot2::from_angle(radians(90.0))));
+    assert!(a.concat(&a.inverse()).fuzzy_eq(&drot2::identity()));
+
+    assert!(b.concat(&b).rotate(&v).fuzzy_eq(&dvec2::new(-0.5, sqrt(0.75))));
+    assert!(drot2::from_angle(radians(90.0)).rotate(&v).fuzzy_eq(&dvec2::new(0.0, 1.0)));
+
+    // `Mat2::from_angle` rotates clockwise
+    assert!(a.to_mat2().fuzzy_eq(&dmat2::from_angle(radians(-30.0))));
+    assert!(a.to_mat2().mul_v(&v).fuzzy_eq(&a.rotate(&v)));
+    assert!(a.to_mat3().fuzzy_eq(&dmat2::from_angle(radians(-30.0)).to_mat3()));
+
+    assert!(drot2::new(2.0, 0.0).normalize() == drot2::identity());
+}
+
+#[test]
+fn test_rot2_slerp() {
+    let a = drot2::from_angle(radians(30.0));
+    let b = drot2::from_angle(radians(90.0));
+
+    assert!(a.slerp(&b, 0.0).fuzzy_eq(&a));
+    assert!(a.slerp(&b, 1.0).fuzzy_eq(&b));
+    assert!(a.slerp(&b, 0.5).fuzzy_eq(&drot2::from_angle(radians(60.0))));
+
+    // takes the shortest arc across the ±π boundary
+    let c = drot2::from_angle(radians(170.0));
+    let d = drot2::from_angle(radians(-170.0));
+    assert!(c.slerp(&d, 0.5).fuzzy_eq(&drot2::from_angle(radians(180.0))));
+}
+
+fn check_rotation2<R: Rotation2<f64>>(a: &R, b: &R) {
+    let v = dvec2::new(1.0, 2.0);
+    let r: R = Rotation2::identity();
+
+    assert!(r.rotate_vector(&v).fuzzy_eq(&v));
+    assert!(a.concat(b).rotate_vector(&v).fuzzy_eq(&a.rotate_vector(&b.rotate_vector(&v))));
+    assert!(a.invert().rotate_vector(&a.rotate_vector(&v)).fuzzy_eq(&v));
+    assert!(a.to_mat2().mul_v(&v).fuzzy_eq(&a.rotate_vector(&v)));
+    assert!(a.slerp(b, 0.0).to_mat2().fuzzy_eq(&a.to_mat2()));
+    assert!(a.slerp(b, 1.0).to_mat2().fuzzy_eq(&b.to_mat2()));
+}
+
+fn check_rotation3<R: Rotation3<f64>>(a: &R, b: &R) {
+    let v = dvec3::new(1.0, 2.0, 3.0);
+    let r: R = Rotation3::identity();
+
+    assert!(r.rotate_vector(&v).fuzzy_eq(&v));
+    assert!(a.concat(b).rotate_vector(&v).fuzzy_eq(&a.rotate_vector(&b.rotate_vector(&v))));
+    assert!(a.invert().rotate_vector(&a.rotate_vector(&v)).fuzzy_eq(&v));
+    assert!(a.to_mat3().mul_v(&v).fuzzy_eq(&a.rotate_vector(&v)));
+    assert!(a.slerp(b, 0.0).to_mat3().fuzzy_eq(&a.to_mat3()));
+    assert!(a.slerp(b, 1.0).to_mat3().fuzzy_eq(&b.to_mat3()));
+}
+
+#[test]
+fn test_rotation2() {
+    check_rotation2(&drot2::from_angle(radians(30.0)), &drot2::from_angle(radians(100.0)));
+    check_rotation2(&dmat2::from_angle(radians(30.0)), &dmat2::from_angle(radians(100.0)));
+
+    let m = dmat2::from_angle(radians(30.0)).slerp(&dmat2::from_angle(radians(90.0)), 0.5);
+    assert!(m.fuzzy_eq(&dmat2::from_angle(radians(60.0))));
+}
+
+#[test]
+fn test_rotation3() {
+    let axis = dvec3::new(1.0, 2.0, 3.0).normalize();
+    let a = dquat::from_angle_axis(radians(30.0), &axis);
+    let b = dquat::from_angle_axis(radians(100.0), &dvec3::new(0.0, 1.0, 0.0));
+
+    check_rotation3(&a, &b);
+    check_rotation3(&a.to_mat3(), &b.to_mat3());
+    check_rotation3(&Basis3::from_quat(&a), &Basis3::from_quat(&b));
+
+    assert!(Basis3::from_angle_axis(radians(30.0), &axis).to_mat3().fuzzy_eq(&a.to_mat3()));
+    assert!(Basis3::from_quat(&a).to_quat().rotation_fuzzy_eq(&a));
+}
+
+fn midpoint3<R: Rotation3<f64>>(a: &R, b: &R) -> R {
+    a.slerp(b, 0.5)
+}
+
+#[test]
+fn test_rotation3_shortest_arc() {
+    let z = dvec3::new(0.0, 0.0, 1.0);
+    let q = dquat::from_angle_axis(radians(10.0), &z);
+    let r = dquat::from_angle_axis(radians(50.0), &z);
+
+    // `-r` is the same rotation as `r`, so the midpoint is still 30 degrees
+    assert!(midpoint3(&q, &-r).rotation_fuzzy_eq(&dquat::from_angle_axis(radians(30.0), &z)));
+    assert!(midpoint3(&q, &r).rotation_fuzzy_eq(&dquat::from_angle_axis(radians(30.0), &z)));
+
+    // the quaternions of these matrices lie in opposite hemispheres, but the
+    // rotations are only 90 degrees apart, through the half turn
+    let a = dmat3::from_angle_z(radians(-100.0));
+    let b = dmat3::from_angle_z(radians(170.0));
+    assert!(a.to_quat().dot(&b.to_quat()) < 0.0);
+
+    let mid = dmat3::from_angle_z(radians(-145.0));
+    assert!(midpoint3(&a, &b).fuzzy_eq(&mid));
+    assert!(midpoint3(&Basis3::from_quat(&a.to_quat()), &Basis3::from_quat(&b.to_quat())).to_mat3().fuzzy_eq(&mid));
+}
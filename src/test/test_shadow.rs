@@ -0,0 +1,80 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use camera::*;
+use frustum::*;
+use mat::*;
+use projection::*;
+use shadow::*;
+use vec::*;
+
+#[test]
+fn test_cube_face_view() {
+    let eye = dvec3::new(1.0, 2.0, 3.0);
+
+    // the direction, right and up vectors of each face
+    let faces = [
+        (PositiveX, dvec3::new( 1.0,  0.0,  0.0), dvec3::new( 0.0,  0.0, -1.0), dvec3::new(0.0, -1.0,  0.0)),
+        (NegativeX, dvec3::new(-1.0,  0.0,  0.0), dvec3::new( 0.0,  0.0,  1.0), dvec3::new(0.0, -1.0,  0.0)),
+        (PositiveY, dvec3::new( 0.0,  1.0,  0.0), dvec3::new( 1.0,  0.0,  0.0), dvec3::new(0.0,  0.0,  1.0)),
+        (NegativeY, dvec3::new( 0.0, -1.0,  0.0), dvec3::new( 1.0,  0.0,  0.0), dvec3::new(0.0,  0.0, -1.0)),
+        (PositiveZ, dvec3::new( 0.0,  0.0,  1.0), dvec3::new( 1.0,  0.0,  0.0), dvec3::new(0.0, -1.0,  0.0)),
+        (NegativeZ, dvec3::new( 0.0,  0.0, -1.0), dvec3::new(-1.0,  0.0,  0.0), dvec3::new(0.0, -1.0,  0.0)),
+    ];
+
+    let views = cube_face_views(&eye);
+
+    for faces.eachi |i, &(face, dir, right, up)| {
+        let view = cube_face_view(face, &eye);
+        assert!(view == views[i]);
+
+        let to_view = |v: &dvec3| -> dvec4 {
+            let p = eye.add_v(v);
+            view.mul_v(&dvec4::new(p.x, p.y, p.z, 1.0))
+        };
+
+        assert!(to_view(&dir).fuzzy_eq(&dvec4::new(0.0, 0.0, -1.0, 1.0)));
+        assert!(to_view(&right).fuzzy_eq(&dvec4::new(1.0, 0.0, 0.0, 1.0)));
+        assert!(to_view(&up).fuzzy_eq(&dvec4::new(0.0, 1.0, 0.0, 1.0)));
+    }
+
+    assert!(cube_face_projection(1.0, 100.0).fuzzy_eq(&perspective(90.0, 1.0, 1.0, 100.0)));
+}
+
+#[test]
+fn test_cascade_splits() {
+    let splits = cascade_splits(1.0, 100.0, 2, 1.0);
+    assert!(splits.len() == 3);
+    assert!(splits[0] == 1.0);
+    assert!(splits[1].fuzzy_eq(&10.0));
+    assert!(splits[2] == 100.0);
+
+    let splits = cascade_splits(1.0, 100.0, 2, 0.0);
+    assert!(splits[1].fuzzy_eq(&50.5));
+
+    let splits = cascade_splits(1.0, 100.0, 2, 0.5);
+    assert!(splits[1].fuzzy_eq(&30.25));
+
+    let splits = cascade_splits(1.0, 100.0, 1, 0.5);
+    assert!(splits == ~[1.0, 100.0]);
+}
+
+#[test]
+fn test_fit_orthographic() {
+    let view = dmat4::identity();
+    let frustum: Frustum<f64> = Frustum::from_mat4(&perspective(90.0, 1.0, 1.0, 2.0));
+    let corners = frustum.corners();
+
+    // looking down the same axis as the camera
+    let light_view = dmat4::identity();
+    assert!(fit_orthographic(&corners, &light_view).fuzzy_eq(&orthographic(-2.0, 2.0, -2.0, 2.0, 1.0, 2.0)));
+
+    // looking down from above
+    let light_view = look_at(&dvec3::new(0.0, 0.0, 0.0), &dvec3::new(0.0, -1.0, 0.0), &dvec3::new(0.0, 0.0, -1.0));
+    assert!(fit_orthographic(&corners, &light_view).fuzzy_eq(&orthographic(-2.0, 2.0, 1.0, 2.0, -2.0, 2.0)));
+
+    let projs = cascade_projections(&view, 90.0, 1.0, &[1.0, 2.0, 4.0], &dmat4::identity());
+    assert!(projs.len() == 2);
+    assert!(projs[0].fuzzy_eq(&orthographic(-2.0, 2.0, -2.0, 2.0, 1.0, 2.0)));
+    assert!(projs[1].fuzzy_eq(&orthographic(-4.0, 4.0, -4.0, 4.0, 2.0, 4.0)));
+}
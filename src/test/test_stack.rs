@@ -0,0 +1,53 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::*;
+use stack::*;
+use vec::*;
+
+#[test]
+fn test_matrix_stack() {
+    let mut s: MatrixStack<f64> = MatrixStack::new();
+
+    assert!(s.depth() == 1);
+    assert!(s.top() == dmat4::identity());
+    assert!(s.pop().is_err());
+    assert!(s.depth() == 1);
+
+    s.translate(1.0, 2.0, 3.0);
+    assert!(s.top() == dmat4::new(1.0, 0.0, 0.0, 0.0,
+                                  0.0, 1.0, 0.0, 0.0,
+                                  0.0, 0.0, 1.0, 0.0,
+                                  1.0, 2.0, 3.0, 1.0));
+
+    s.push();
+    assert!(s.depth() == 2);
+    s.scale(2.0, 2.0, 2.0);
+    s.load_identity();
+    assert!(s.top() == dmat4::identity());
+    assert!(s.pop().is_ok());
+    assert!(s.top().w == dvec4::new(1.0, 2.0, 3.0, 1.0));
+    assert!(s.pop().is_err());
+}
+
+#[test]
+fn test_matrix_stack_order() {
+    let mut s: MatrixStack<f64> = MatrixStack::new();
+    let p = dvec4::new(1.0, 0.0, 0.0, 1.0);
+
+    // as in OpenGL the last transformation specified is applied first
+    s.translate(10.0, 0.0, 0.0);
+    s.rotate(90.0, 0.0, 0.0, 2.0);
+    s.scale(3.0, 1.0, 1.0);
+    assert!(s.top().mul_v(&p).fuzzy_eq(&dvec4::new(10.0, 3.0, 0.0, 1.0)));
+
+    s.load_identity();
+    s.rotate(90.0, 0.0, 0.0, 1.0);
+    s.pre_mult_matrix(&dmat3::from_value(3.0).to_mat4());
+    assert!(s.top().mul_v(&p).fuzzy_eq(&dvec4::new(0.0, 3.0, 0.0, 1.0)));
+
+    let m = dmat3::from_angle_x(radians(30.0)).to_mat4();
+    s.load_matrix(&m);
+    s.mult_matrix(&m);
+    assert!(s.top().fuzzy_eq(&m.mul_m(&m)));
+}
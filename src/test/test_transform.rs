@@ -0,0 +1,100 @@
+use std::cmp::FuzzyEq;
+use numeric::*;
+
+use mat::*;
+use quat::*;
+use rot::*;
+use transform::*;
+use vec::*;
+
+#[test]
+fn test_transform3() {
+    let axis = dvec3::new(1.0, 2.0, 3.0).normalize();
+    let a = Transform3::new(dvec3::from_value(2.0),
+                            dquat::from_angle_axis(radians(30.0), &axis),
+                            dvec3::new(1.0, 2.0, 3.0));
+    let b = Transform3::new(dvec3::new(1.0, 2.0, 3.0),
+                            dquat::from_angle_axis(radians(-60.0), &dvec3::new(0.0, 1.0, 0.0)),
+                            dvec3::new(-4.0, 5.0, 0.5));
+    let p = dvec3::new(3.0, -1.0, 2.0);
+
+    let ident: Transform3<f64> = Transform3::identity();
+    assert!(ident.transform_point(&p) == p);
+    assert!(ident.to_mat4() == dmat4::identity());
+
+    assert!(a.transform_point(&p).fuzzy_eq(&a.transform_vector(&p).add_v(&a.disp)));
+    assert!(a.transform_vector(&p).fuzzy_eq(&a.rot.mul_v(&p.mul_t(2.0))));
+
+    assert!(a.concat(&b).transform_point(&p).fuzzy_eq(&a.transform_point(&b.transform_point(&p))));
+    assert!(a.invert().transform_point(&a.transform_point(&p)).fuzzy_eq(&p));
+    assert!(a.concat(&a.invert()).fuzzy_eq(&ident));
+
+    let m = b.to_mat4();
+    let q = b.transform_point(&p);
+    assert!(m.mul_v(&dvec4::new(p.x, p.y, p.z, 1.0)).fuzzy_eq(&dvec4::new(q.x, q.y, q.z, 1.0)));
+    assert!(Transform3::from_mat4(&m).unwrap().fuzzy_eq(&b));
+    assert!(Transform3::from_mat4(&a.to_mat4()).unwrap().fuzzy_eq(&a));
+
+    assert!(a.interpolate(&b, 0.0).fuzzy_eq(&a));
+    assert!(a.interpolate(&b, 1.0).fuzzy_eq(&b));
+    assert!(a.interpolate(&b, 0.5).disp.fuzzy_eq(&dvec3::new(-1.5, 3.5, 1.75)));
+}
+
+#[test]
+fn test_transform3_from_mat4() {
+    // reflections are stored as a negative scale
+    let r = Transform3::from_mat4(&dmat3::new(-1.0, 0.0, 0.0,
+                                               0.0, 1.0, 0.0,
+                                               0.0, 0.0, 1.0).to_mat4()).unwrap();
+    assert!(r.scale == dvec3::new(-1.0, 1.0, 1.0));
+    assert!(r.rot.rotation_fuzzy_eq(&dquat::identity()));
+
+    // shears, projections and degenerate scales can not be decomposed
+    assert!(Transform3::from_mat4(&dmat3::new(1.0, 0.0, 0.0,
+                                              1.0, 1.0, 0.0,
+                                              0.0, 0.0, 1.0).to_mat4()).is_none());
+    assert!(Transform3::from_mat4(&dmat3::from_value(0.0).to_mat4()).is_none());
+
+    let mut p = dmat4::identity();
+    p.z.w = -1.0;
+    assert!(Transform3::from_mat4(&p).is_none());
+}
+
+#[test]
+fn test_transform3_interpolate_shortest_arc() {
+    // the decomposed rotations lie in opposite hemispheres, but are only 90
+    // degrees apart
+    let a = Transform3::from_mat4(&dmat3::from_angle_z(radians(-100.0)).to_mat4()).unwrap();
+    let b = Transform3::from_mat4(&dmat3::from_angle_z(radians(170.0)).to_mat4()).unwrap();
+    assert!(a.rot.dot(&b.rot) < 0.0);
+
+    let mid = a.interpolate(&b, 0.5);
+    assert!(mid.rot.to_mat3().fuzzy_eq(&dmat3::from_angle_z(radians(-145.0))));
+    assert!(mid.scale.fuzzy_eq(&dvec3::from_value(1.0)));
+}
+
+#[test]
+fn test_transform2() {
+    let a = Transform2::new(dvec2::from_value(2.0), drot2::from_angle(radians(30.0)), dvec2::new(1.0, 2.0));
+    let b = Transform2::new(dvec2::new(1.0, 3.0), drot2::from_angle(radians(-80.0)), dvec2::new(-4.0, 5.0));
+    let p = dvec2::new(3.0, -1.0);
+
+    let ident: Transform2<f64> = Transform2::identity();
+    assert!(ident.transform_point(&p) == p);
+    assert!(ident.to_mat3() == dmat3::identity());
+
+    assert!(a.concat(&b).transform_point(&p).fuzzy_eq(&a.transform_point(&b.transform_point(&p))));
+    assert!(a.invert().transform_point(&a.transform_point(&p)).fuzzy_eq(&p));
+
+    let m = b.to_mat3();
+    let q = b.transform_point(&p);
+    assert!(m.mul_v(&dvec3::new(p.x, p.y, 1.0)).fuzzy_eq(&dvec3::new(q.x, q.y, 1.0)));
+    assert!(Transform2::from_mat3(&m).unwrap().fuzzy_eq(&b));
+
+    assert!(a.interpolate(&b, 0.0).fuzzy_eq(&a));
+    assert!(a.interpolate(&b, 1.0).fuzzy_eq(&b));
+
+    assert!(Transform2::from_mat3(&dmat3::new(1.0, 0.0, 0.0,
+                                              1.0, 1.0, 0.0,
+                                              0.0, 0.0, 1.0)).is_none());
+}
@@ -54,6 +54,9 @@ fn test_vec2() {
     assert!(a.mul_v(&b) == vec2::new(    3.0,     8.0));
     assert!(a.div_v(&b) == vec2::new(1.0/3.0, 2.0/4.0));
     
+    assert!(a.complex_mul(&b) == vec2::new(-5.0, 10.0));
+    assert!(a.complex_mul(&vec2::unit_x()) == a);
+    
     mut_a.neg_self();
     assert!(mut_a == -a);
     mut_a = a;
@@ -452,4 +455,41 @@ fn test_vec4_boolean() {
     assert!(tttt.any() == true);
     assert!(tttt.all() == true);
     assert!(tttt.not() == bvec4::new(false, false, false, false));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_from_homogeneous() {
+    let h = dvec4::new(2.0, 4.0, -6.0, 2.0);
+
+    let v: dvec3 = FromHomogeneous::from_homogeneous(&h);
+    assert!(v == dvec3::new(1.0, 2.0, -3.0));
+
+    let (v, w): (dvec3, f64) = FromHomogeneous::from_homogeneous_with_w(&h);
+    assert!(v == dvec3::new(1.0, 2.0, -3.0));
+    assert!(w == 2.0);
+
+    // a negative w keeps the sign that the divide loses
+    let (v, w): (dvec3, f64) = FromHomogeneous::from_homogeneous_checked_with_w(&h.mul_t(-1.0)).unwrap();
+    assert!(v == dvec3::new(1.0, 2.0, -3.0));
+    assert!(w == -2.0);
+
+    let v: Option<dvec3> = FromHomogeneous::from_homogeneous_checked(&h);
+    assert!(v == Some(dvec3::new(1.0, 2.0, -3.0)));
+
+    // directions have no finite position
+    let d = dvec3::new(1.0, 2.0, 3.0).to_homogeneous();
+    let v: Option<dvec3> = FromHomogeneous::from_homogeneous_checked(&d);
+    assert!(v.is_none());
+    let v: Option<(dvec3, f64)> = FromHomogeneous::from_homogeneous_checked_with_w(&d);
+    assert!(v.is_none());
+
+    let v: dvec2 = FromHomogeneous::from_homogeneous(&dvec3::new(3.0, -6.0, 3.0));
+    assert!(v == dvec2::new(1.0, -2.0));
+
+    let (v, w): (dvec2, f64) = FromHomogeneous::from_homogeneous_with_w(&dvec3::new(3.0, -6.0, 3.0));
+    assert!(v == dvec2::new(1.0, -2.0));
+    assert!(w == 3.0);
+
+    let v: Option<dvec2> = FromHomogeneous::from_homogeneous_checked(&dvec3::new(3.0, -6.0, 1.0e-10));
+    assert!(v.is_none());
+}
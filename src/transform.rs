@@ -0,0 +1,359 @@
+use core::num::{Zero, One};
+use core::num::Zero::zero;
+use core::num::One::one;
+use std::cmp::{FuzzyEq, FUZZY_EPSILON};
+use numeric::*;
+
+use mat::{Mat3, BaseMat3, Mat4, BaseMat4};
+use quat::Quat;
+use rot::Rot2;
+use vec::{Vec2, BaseVec2, Vec3, BaseVec3, BaseVec4, AffineVec, NumVec, NumVec2, NumVec3, ToHomogeneous};
+
+/**
+ * A 3-dimensional transformation made up of a scale, followed by a rotation,
+ * followed by a translation
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `scale` - the scale factor along each axis
+ * * `rot`   - the rotation, which should be normalized
+ * * `disp`  - the translation
+ */
+#[deriving(Eq)]
+pub struct Transform3<T> { scale: Vec3<T>, rot: Quat<T>, disp: Vec3<T> }
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Transform3<T> {
+    #[inline(always)]
+    fn new(scale: Vec3<T>, rot: Quat<T>, disp: Vec3<T>) -> Transform3<T> {
+        Transform3 { scale: scale, rot: rot, disp: disp }
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation that leaves points and vectors unchanged
+     */
+    #[inline(always)]
+    fn identity() -> Transform3<T> {
+        Transform3::new(NumVec::identity(), Quat::identity(), NumVec::zero())
+    }
+
+    /**
+     * Decompose a matrix into a scale, rotation and translation
+     *
+     * # Return value
+     *
+     * * `Some(t)` - if the matrix is an affine transformation without shear
+     * * `None` - if the matrix has a projective part, a shear, or a zero scale
+     *   along one of its axes
+     *
+     * A reflection is represented by negating the scale along the `x` axis.
+     */
+    fn from_mat4(m: &Mat4<T>) -> Option<Transform3<T>> {
+        if !(m[0][3].fuzzy_eq(&zero()) && m[1][3].fuzzy_eq(&zero()) &&
+             m[2][3].fuzzy_eq(&zero()) && m[3][3].fuzzy_eq(&one())) {
+            return None;
+        }
+
+        let m3 = m.to_mat3();
+        let mut sx = m3[0].length();
+        let sy = m3[1].length();
+        let sz = m3[2].length();
+
+        if sx.fuzzy_eq(&zero()) || sy.fuzzy_eq(&zero()) || sz.fuzzy_eq(&zero()) {
+            return None;
+        }
+
+        let mut x = m3[0].div_t(sx);
+        let y = m3[1].div_t(sy);
+        let z = m3[2].div_t(sz);
+
+        if !(x.dot(&y).fuzzy_eq(&zero()) && x.dot(&z).fuzzy_eq(&zero()) && y.dot(&z).fuzzy_eq(&zero())) {
+            return None;
+        }
+
+        if x.cross(&y).dot(&z) < zero() {
+            sx = -sx;
+            x = -x;
+        }
+
+        let r: Mat3<T> = BaseMat3::from_cols(x, y, z);
+
+        Some(Transform3::new(BaseVec3::new(sx, sy, sz),
+                             r.to_quat(),
+                             BaseVec3::new(m[3][0], m[3][1], m[3][2])))
+    }
+
+    /**
+     * # Return value
+     *
+     * The point transformed by the scale, rotation and translation
+     */
+    #[inline(always)]
+    fn transform_point(&self, point: &Vec3<T>) -> Vec3<T> {
+        self.transform_vector(point).add_v(&self.disp)
+    }
+
+    /**
+     * # Return value
+     *
+     * The vector transformed by the scale and rotation. Unlike a point, a
+     * vector is not affected by the translation.
+     */
+    #[inline(always)]
+    fn transform_vector(&self, vec: &Vec3<T>) -> Vec3<T> {
+        self.rot.mul_v(&vec.mul_v(&self.scale))
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation that applies `other` followed by the transformation
+     *
+     * The result is exact when the scale of the transformation is uniform.
+     * Otherwise a rotation followed by a non-uniform scale can introduce a
+     * shear, which can not be represented, and the scales are simply
+     * multiplied.
+     */
+    #[inline(always)]
+    fn concat(&self, other: &Transform3<T>) -> Transform3<T> {
+        Transform3::new(self.scale.mul_v(&other.scale),
+                        self.rot.mul_q(&other.rot),
+                        self.transform_point(&other.disp))
+    }
+
+    /**
+     * # Return value
+     *
+     * The inverse transformation. As with `concat`, this is exact when the
+     * scale is uniform.
+     */
+    #[inline(always)]
+    fn invert(&self) -> Transform3<T> {
+        let scale = NumVec::identity::<T,Vec3<T>>().div_v(&self.scale);
+        let rot = self.rot.conjugate();
+        let disp = rot.mul_v(&self.disp).mul_v(&scale).neg();
+
+        Transform3::new(scale, rot, disp)
+    }
+
+    /**
+     * Interpolate between the transformation and `other`
+     *
+     * The scale and translation are linearly interpolated, and the rotation
+     * is spherically interpolated along the shortest arc.
+     */
+    #[inline(always)]
+    fn interpolate(&self, other: &Transform3<T>, amount: T) -> Transform3<T> {
+        Transform3::new(self.scale.lerp(&other.scale, amount),
+                        self.rot.slerp_shortest(&other.rot, amount),
+                        self.disp.lerp(&other.disp, amount))
+    }
+
+    /**
+     * Convert the transformation to a 4 x 4 matrix, ie: `T * R * S`
+     */
+    #[inline(always)]
+    fn to_mat4(&self) -> Mat4<T> {
+        let r = self.rot.to_mat3();
+
+        BaseMat4::from_cols(r[0].mul_t(self.scale.x).to_homogeneous(),
+                            r[1].mul_t(self.scale.y).to_homogeneous(),
+                            r[2].mul_t(self.scale.z).to_homogeneous(),
+                            BaseVec4::new(self.disp.x, self.disp.y, self.disp.z, one()))
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FuzzyEq<T> for Transform3<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Transform3<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Transform3<T>, epsilon: &T) -> bool {
+        self.scale.fuzzy_eq_eps(&other.scale, epsilon) &&
+        self.rot.rotation_fuzzy_eq_eps(&other.rot, epsilon) &&
+        self.disp.fuzzy_eq_eps(&other.disp, epsilon)
+    }
+}
+
+/**
+ * A 2-dimensional transformation made up of a scale, followed by a rotation,
+ * followed by a translation
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. Should be a floating point type.
+ *
+ * # Fields
+ *
+ * * `scale` - the scale factor along each axis
+ * * `rot`   - the rotation, which should be normalized
+ * * `disp`  - the translation
+ */
+#[deriving(Eq)]
+pub struct Transform2<T> { scale: Vec2<T>, rot: Rot2<T>, disp: Vec2<T> }
+
+pub impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> Transform2<T> {
+    #[inline(always)]
+    fn new(scale: Vec2<T>, rot: Rot2<T>, disp: Vec2<T>) -> Transform2<T> {
+        Transform2 { scale: scale, rot: rot, disp: disp }
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation that leaves points and vectors unchanged
+     */
+    #[inline(always)]
+    fn identity() -> Transform2<T> {
+        Transform2::new(NumVec::identity(), Rot2::identity(), NumVec::zero())
+    }
+
+    /**
+     * Decompose a 3 x 3 matrix acting on 2-dimensional homogeneous
+     * coordinates into a scale, rotation and translation
+     *
+     * # Return value
+     *
+     * * `Some(t)` - if the matrix is an affine transformation without shear
+     * * `None` - if the matrix has a projective part, a shear, or a zero scale
+     *   along one of its axes
+     *
+     * A reflection is represented by negating the scale along the `x` axis.
+     */
+    fn from_mat3(m: &Mat3<T>) -> Option<Transform2<T>> {
+        if !(m[0][2].fuzzy_eq(&zero()) && m[1][2].fuzzy_eq(&zero()) && m[2][2].fuzzy_eq(&one())) {
+            return None;
+        }
+
+        let mut x: Vec2<T> = BaseVec2::new(m[0][0], m[0][1]);
+        let y: Vec2<T> = BaseVec2::new(m[1][0], m[1][1]);
+
+        let mut sx = x.length();
+        let sy = y.length();
+
+        if sx.fuzzy_eq(&zero()) || sy.fuzzy_eq(&zero()) {
+            return None;
+        }
+
+        x = x.div_t(sx);
+
+        if !x.dot(&y.div_t(sy)).fuzzy_eq(&zero()) {
+            return None;
+        }
+
+        if x.perp_dot(&y) < zero() {
+            sx = -sx;
+            x = -x;
+        }
+
+        Some(Transform2::new(BaseVec2::new(sx, sy),
+                             Rot2::new(x.x, x.y),
+                             BaseVec2::new(m[2][0], m[2][1])))
+    }
+
+    /**
+     * # Return value
+     *
+     * The point transformed by the scale, rotation and translation
+     */
+    #[inline(always)]
+    fn transform_point(&self, point: &Vec2<T>) -> Vec2<T> {
+        self.transform_vector(point).add_v(&self.disp)
+    }
+
+    /**
+     * # Return value
+     *
+     * The vector transformed by the scale and rotation. Unlike a point, a
+     * vector is not affected by the translation.
+     */
+    #[inline(always)]
+    fn transform_vector(&self, vec: &Vec2<T>) -> Vec2<T> {
+        self.rot.rotate(&vec.mul_v(&self.scale))
+    }
+
+    /**
+     * # Return value
+     *
+     * The transformation that applies `other` followed by the transformation
+     *
+     * The result is exact when the scale of the transformation is uniform.
+     */
+    #[inline(always)]
+    fn concat(&self, other: &Transform2<T>) -> Transform2<T> {
+        Transform2::new(self.scale.mul_v(&other.scale),
+                        self.rot.concat(&other.rot),
+                        self.transform_point(&other.disp))
+    }
+
+    /**
+     * # Return value
+     *
+     * The inverse transformation. As with `concat`, this is exact when the
+     * scale is uniform.
+     */
+    #[inline(always)]
+    fn invert(&self) -> Transform2<T> {
+        let scale = NumVec::identity::<T,Vec2<T>>().div_v(&self.scale);
+        let rot = self.rot.inverse();
+        let disp = rot.rotate(&self.disp).mul_v(&scale).neg();
+
+        Transform2::new(scale, rot, disp)
+    }
+
+    /**
+     * Interpolate between the transformation and `other`
+     *
+     * The scale and translation are linearly interpolated, and the rotation
+     * is spherically interpolated.
+     */
+    #[inline(always)]
+    fn interpolate(&self, other: &Transform2<T>, amount: T) -> Transform2<T> {
+        Transform2::new(self.scale.lerp(&other.scale, amount),
+                        self.rot.slerp(&other.rot, amount),
+                        self.disp.lerp(&other.disp, amount))
+    }
+
+    /**
+     * Convert the transformation to a 3 x 3 matrix acting on 2-dimensional
+     * homogeneous coordinates
+     */
+    #[inline(always)]
+    fn to_mat3(&self) -> Mat3<T> {
+        let r = self.rot.to_mat2();
+
+        BaseMat3::new(r[0][0] * self.scale.x, r[0][1] * self.scale.x, zero(),
+                      r[1][0] * self.scale.y, r[1][1] * self.scale.y, zero(),
+                      self.disp.x,            self.disp.y,            one())
+    }
+}
+
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FuzzyEq<T> for Transform2<T> {
+    #[inline(always)]
+    fn fuzzy_eq(&self, other: &Transform2<T>) -> bool {
+        self.fuzzy_eq_eps(other, &num::cast(FUZZY_EPSILON))
+    }
+
+    #[inline(always)]
+    fn fuzzy_eq_eps(&self, other: &Transform2<T>, epsilon: &T) -> bool {
+        self.scale.fuzzy_eq_eps(&other.scale, epsilon) &&
+        self.rot.fuzzy_eq_eps(&other.rot, epsilon) &&
+        self.disp.fuzzy_eq_eps(&other.disp, epsilon)
+    }
+}
+
+// Rust-style type aliases
+pub type Transform2f   = Transform2<float>;
+pub type Transform2f32 = Transform2<f32>;
+pub type Transform2f64 = Transform2<f64>;
+
+pub type Transform3f   = Transform3<float>;
+pub type Transform3f32 = Transform3<f32>;
+pub type Transform3f64 = Transform3<f64>;
@@ -176,6 +176,14 @@ pub trait NumVec2<T>: NumVec<T> {
      * The perp dot product of the vector and `other`
      */
     fn perp_dot(&self, other: &Self) -> T;
+
+    /**
+     * # Return value
+     *
+     * The product of the vector and `other` when both are treated as complex
+     * numbers, with `x` as the real part and `y` as the imaginary part
+     */
+    fn complex_mul(&self, other: &Self) -> Self;
 }
 
 /**
@@ -212,10 +220,74 @@ pub trait NumVec4<T>: NumVec<T> {
 pub trait ToHomogeneous<H> {
     /**
      * Convert to a homogenous coordinate
+     *
+     * Vectors are directions, so they are given a `w` of zero and are not
+     * affected by translations. Positions should use the types in `point`,
+     * which are given a `w` of one.
      */
     fn to_homogeneous(&self) -> H;
 }
 
+/**
+ * Conversion from homogeneous coordinates, by the perspective divide
+ *
+ * # Type parameters
+ *
+ * * `T` - The type of the components. This should be a floating point type.
+ * * `H` - The type of the homogeneous coordinate
+ */
+pub trait FromHomogeneous<T, H> {
+    /**
+     * Convert from a homogeneous coordinate, dividing by the last component
+     */
+    fn from_homogeneous(h: &H) -> Self;
+
+    /**
+     * Convert from a homogeneous coordinate, dividing by the last component
+     *
+     * # Return value
+     *
+     * * `Some(v)` - the converted value
+     * * `None` - if the last component is approximately zero, ie: the
+     *   coordinate is a direction or a point at infinity
+     */
+    fn from_homogeneous_checked(h: &H) -> Option<Self>;
+
+    /**
+     * Convert from a homogeneous coordinate, dividing by the last component
+     *
+     * # Return value
+     *
+     * The converted value and the last component. The sign of the last
+     * component distinguishes points in front of the camera from those
+     * behind it, which the divide loses.
+     */
+    fn from_homogeneous_with_w(h: &H) -> (Self, T);
+
+    /**
+     * Convert from a homogeneous coordinate, dividing by the last component
+     *
+     * # Return value
+     *
+     * * `Some((v, w))` - the converted value and the last component
+     * * `None` - if the last component is approximately zero
+     */
+    fn from_homogeneous_checked_with_w(h: &H) -> Option<(Self, T)>;
+}
+
+/**
+ * The perspective divide shared by the `FromHomogeneous` implementations
+ *
+ * # Return value
+ *
+ * * `Some(divide())` - if `w` is not approximately zero
+ * * `None` - otherwise, without calling `divide`
+ */
+#[inline(always)]
+fn divide_checked<T:Copy + Zero + FuzzyEq<T>, U>(w: T, divide: &fn() -> U) -> Option<U> {
+    if w.fuzzy_eq(&zero()) { None } else { Some(divide()) }
+}
+
 /**
  * A Euclidean (or Affine) vector
  *
@@ -674,6 +746,12 @@ impl<T:Copy + Number + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> +
     fn perp_dot(&self, other: &Vec2<T>) ->T {
         (self[0] * other[1]) - (self[1] * other[0])
     }
+
+    #[inline(always)]
+    fn complex_mul(&self, other: &Vec2<T>) -> Vec2<T> {
+        BaseVec2::new((self[0] * other[0]) - (self[1] * other[1]),
+                      (self[0] * other[1]) + (self[1] * other[0]))
+    }
 }
 
 impl<T:Copy + Number + Zero> ToHomogeneous<Vec3<T>> for Vec2<T> {
@@ -683,6 +761,28 @@ impl<T:Copy + Number + Zero> ToHomogeneous<Vec3<T>> for Vec2<T> {
     }
 }
 
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FromHomogeneous<T, Vec3<T>> for Vec2<T> {
+    #[inline(always)]
+    fn from_homogeneous(h: &Vec3<T>) -> Vec2<T> {
+        BaseVec2::new(h.x / h.z, h.y / h.z)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked(h: &Vec3<T>) -> Option<Vec2<T>> {
+        divide_checked(h.z, || FromHomogeneous::from_homogeneous(h))
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_with_w(h: &Vec3<T>) -> (Vec2<T>, T) {
+        (FromHomogeneous::from_homogeneous(h), h.z)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked_with_w(h: &Vec3<T>) -> Option<(Vec2<T>, T)> {
+        divide_checked(h.z, || FromHomogeneous::from_homogeneous_with_w(h))
+    }
+}
+
 impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> AffineVec<T> for Vec2<T> {
     #[inline(always)]
     fn length2(&self) -> T {
@@ -1077,6 +1177,28 @@ impl<T:Copy + Number + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> +
     }
 }
 
+impl<T:Copy + Float + Zero + One + FuzzyEq<T> + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> FromHomogeneous<T, Vec4<T>> for Vec3<T> {
+    #[inline(always)]
+    fn from_homogeneous(h: &Vec4<T>) -> Vec3<T> {
+        BaseVec3::new(h.x / h.w, h.y / h.w, h.z / h.w)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked(h: &Vec4<T>) -> Option<Vec3<T>> {
+        divide_checked(h.w, || FromHomogeneous::from_homogeneous(h))
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_with_w(h: &Vec4<T>) -> (Vec3<T>, T) {
+        (FromHomogeneous::from_homogeneous(h), h.w)
+    }
+
+    #[inline(always)]
+    fn from_homogeneous_checked_with_w(h: &Vec4<T>) -> Option<(Vec3<T>, T)> {
+        divide_checked(h.w, || FromHomogeneous::from_homogeneous_with_w(h))
+    }
+}
+
 impl<T:Copy + Float + Zero + One + Add<T,T> + Sub<T,T> + Mul<T,T> + Div<T,T> + Neg<T>> AffineVec<T> for Vec3<T> {
     #[inline(always)]
     fn length2(&self) -> T {